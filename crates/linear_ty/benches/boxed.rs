@@ -0,0 +1,66 @@
+//! Compares transitions of a large state stored inline with transitions through a box.
+//!
+//! Run with `cargo bench --bench boxed`.
+use linear_ty::must_use;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const STATE_SIZE: usize = 16 * 1024;
+const ROUNDS: u32 = 1000;
+
+type State = [u8; STATE_SIZE];
+
+fn step(mut state: State) -> State {
+    state[0] = state[0].wrapping_add(1);
+    black_box(state)
+}
+
+fn step_in_place(state: &mut State) {
+    state[0] = state[0].wrapping_add(1);
+    black_box(state);
+}
+
+/// Eight transitions moving the whole state each time.
+fn inline() -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let state = must_use(black_box([0u8; STATE_SIZE]))
+            .map(step)
+            .map(step)
+            .map(step)
+            .map(step)
+            .map(step)
+            .map(step)
+            .map(step)
+            .map(step);
+        black_box(state.into());
+    }
+    start.elapsed()
+}
+
+/// Eight transitions moving only the box each time.
+fn boxed() -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let state = must_use(black_box([0u8; STATE_SIZE]))
+            .boxed()
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place)
+            .map_boxed(step_in_place);
+        black_box(state.into());
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let inline = inline();
+    let boxed = boxed();
+    println!("8 transitions of a {STATE_SIZE} byte state, {ROUNDS} rounds:");
+    println!("  inline {inline:?}");
+    println!("  boxed  {boxed:?}");
+}
@@ -1,7 +1,7 @@
 // this is the example from the README.md
 //! This example demonstrates how to use the `linear_ty` crate to model a linear state machine
 //! that reads the content of a file.
-use linear_ty::new_linear;
+use linear_ty::{destructure, new_linear};
 use std::fs::File;
 use std::io::{Read, Result};
 
@@ -27,10 +27,10 @@ fn main() {
     // Create a linear type and transition through the states
     let file_content = new_linear!(Filename("README.md"))
         .map(open_file)
-        .map_ok(read_text)
+        .and_then_ok(read_text)
         .unwrap_ok();
 
     // destructure the file content
-    let FileContent(text) = file_content.into();
+    destructure!(file_content => FileContent(text));
     assert!(text.contains("# Example"));
 }
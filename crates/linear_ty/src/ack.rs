@@ -0,0 +1,137 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// Implemented by message queue adapters to acknowledge deliveries. Adapters mint an
+/// `AckToken` per delivery with `token()` and hand it to the handler along with the message.
+pub trait Acknowledger: Sized {
+    /// Identifies a delivery, e.g. a delivery tag or an offset.
+    type Tag;
+    /// Error of acknowledging a delivery.
+    type Error;
+
+    /// Acknowledges the delivery.
+    ///
+    /// # Errors
+    ///
+    /// When the queue fails to acknowledge the delivery.
+    fn ack(&self, tag: Self::Tag) -> Result<(), Self::Error>;
+
+    /// Negatively acknowledges the delivery.
+    ///
+    /// # Errors
+    ///
+    /// When the queue fails to negatively acknowledge the delivery.
+    fn nack(&self, tag: Self::Tag, reason: &str) -> Result<(), Self::Error>;
+
+    /// Mints the token of a delivery.
+    fn token(self, tag: Self::Tag) -> AckToken<Self> {
+        AckToken(__linear_from_parts((self, tag)))
+    }
+}
+
+/// Linear token of a message delivery, it can only be consumed by `ack()` or `nack()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use std::cell::RefCell;
+///
+/// struct Queue(RefCell<Vec<String>>);
+///
+/// impl Acknowledger for &Queue {
+///     type Tag = u64;
+///     type Error = core::convert::Infallible;
+///
+///     fn ack(&self, tag: u64) -> Result<(), Self::Error> {
+///         self.0.borrow_mut().push(format!("ack {tag}"));
+///         Ok(())
+///     }
+///
+///     fn nack(&self, tag: u64, reason: &str) -> Result<(), Self::Error> {
+///         self.0.borrow_mut().push(format!("nack {tag}: {reason}"));
+///         Ok(())
+///     }
+/// }
+///
+/// fn handle((message, token): (&str, AckToken<&Queue>)) {
+///     if message.is_empty() {
+///         token.nack("empty message").unwrap();
+///     } else {
+///         token.ack().unwrap();
+///     }
+/// }
+///
+/// let queue = Queue(RefCell::new(Vec::new()));
+/// handle(("hello", (&queue).token(1)));
+/// handle(("", (&queue).token(2)));
+/// assert_eq!(*queue.0.borrow(), ["ack 1", "nack 2: empty message"]);
+/// ```
+#[must_use]
+pub struct AckToken<A: Acknowledger>(MustUse<(A, A::Tag)>);
+
+impl<A: Acknowledger> AckToken<A> {
+    /// Returns the tag of the delivery.
+    pub fn tag(&self) -> &A::Tag {
+        &self.0.0.1
+    }
+
+    /// Acknowledges the delivery.
+    ///
+    /// # Errors
+    ///
+    /// When the queue fails to acknowledge the delivery.
+    pub fn ack(self) -> Result<(), A::Error> {
+        let (acknowledger, tag) = self.0.into();
+        acknowledger.ack(tag)
+    }
+
+    /// Negatively acknowledges the delivery with a reason.
+    ///
+    /// # Errors
+    ///
+    /// When the queue fails to negatively acknowledge the delivery.
+    pub fn nack(self, reason: &str) -> Result<(), A::Error> {
+        let (acknowledger, tag) = self.0.into();
+        acknowledger.nack(tag, reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Acknowledger;
+    use std::sync::mpsc;
+
+    #[derive(Clone)]
+    struct Adapter(mpsc::Sender<Result<u32, u32>>);
+
+    impl Acknowledger for Adapter {
+        type Tag = u32;
+        type Error = mpsc::SendError<Result<u32, u32>>;
+
+        fn ack(&self, tag: u32) -> Result<(), Self::Error> {
+            self.0.send(Ok(tag))
+        }
+
+        fn nack(&self, tag: u32, _: &str) -> Result<(), Self::Error> {
+            self.0.send(Err(tag))
+        }
+    }
+
+    #[test]
+    fn acknowledged() {
+        let (tx, rx) = mpsc::channel();
+        let adapter = Adapter(tx);
+        let token = adapter.clone().token(1);
+        assert_eq!(token.tag(), &1);
+        token.ack().unwrap();
+        adapter.token(2).nack("invalid").unwrap();
+        assert_eq!(rx.iter().collect::<Vec<_>>(), [Ok(1), Err(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let (tx, _rx) = mpsc::channel();
+        let _ = Adapter(tx).token(1);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{__linear_from_parts, Linear};
+use core::mem::{MaybeUninit, align_of, size_of};
+
+/// Additional `boxed()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Moves the inner value to the heap. Large states then only move a pointer on each
+    /// transition instead of the whole value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let boxed = new_linear!([0u8; 4096]).boxed();
+    /// assert_eq!(boxed.into().len(), 4096);
+    /// ```
+    pub fn boxed(self) -> Linear<Box<T>, Self> {
+        __linear_from_parts(Box::new(self.into()))
+    }
+}
+
+/// Additional methods for `Linear<Box<T>>`.
+impl<T: ?Sized, U> Linear<Box<T>, U> {
+    /// Transforms the boxed inner value in place. The closure gets exclusive access to the
+    /// value which is owned by the linear type at that point. Only the box is moved into the
+    /// new linear type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let boxed = new_linear!([0u8; 4096]).boxed();
+    /// let filled = boxed.map_boxed(|buffer| buffer.fill(1));
+    /// assert!(filled.into().iter().all(|&b| b == 1));
+    /// ```
+    pub fn map_boxed<F: FnOnce(&mut T)>(self, f: F) -> Linear<Box<T>, Self> {
+        let mut inner = self.into();
+        f(&mut inner);
+        __linear_from_parts(inner)
+    }
+
+    /// Moves the inner value out of the box.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let unboxed = new_linear!(123).boxed().unboxed();
+    /// assert_eq!(unboxed.into(), 123);
+    /// ```
+    pub fn unboxed(self) -> Linear<T, Self>
+    where
+        T: Sized,
+    {
+        __linear_from_parts(*self.into())
+    }
+
+    /// Transforms the boxed inner value into a value of another type with the same size and
+    /// alignment, reusing the heap allocation. Types with a different layout are rejected at
+    /// compile time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let text = new_linear!(String::from("abc")).boxed();
+    /// let bytes = text.map_in_place(String::into_bytes);
+    /// assert_eq!(*bytes.into(), b"abc");
+    /// ```
+    pub fn map_in_place<F: FnOnce(T) -> R, R>(self, f: F) -> Linear<Box<R>, Self>
+    where
+        T: Sized,
+    {
+        const {
+            assert!(
+                size_of::<T>() == size_of::<R>() && align_of::<T>() == align_of::<R>(),
+                "map_in_place() requires types with the same size and alignment"
+            );
+        };
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`. When `f` panics the
+        // allocation is freed without dropping the moved out value again.
+        let slot: Box<MaybeUninit<T>> = unsafe { Box::from_raw(Box::into_raw(self.into()).cast()) };
+        // SAFETY: the slot is initialized and not read again.
+        let value = unsafe { slot.assume_init_read() };
+        let result = f(value);
+        // SAFETY: `T` and `R` have the same layout, checked above.
+        let mut slot: Box<MaybeUninit<R>> = unsafe { Box::from_raw(Box::into_raw(slot).cast()) };
+        slot.write(result);
+        // SAFETY: the slot was just initialized.
+        __linear_from_parts(unsafe { slot.assume_init() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    #[test]
+    fn map_in_place_drops_once() {
+        let counter = Rc::new(());
+        let boxed = crate::must_use(Rc::clone(&counter)).boxed();
+        let mapped = boxed.map_in_place(|rc| {
+            assert_eq!(Rc::strong_count(&rc), 2);
+            drop(rc);
+            Rc::clone(&counter)
+        });
+        assert_eq!(Rc::strong_count(&counter), 2);
+        mapped.destroy();
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn map_in_place_panic() {
+        let counter = Rc::new(());
+        let boxed = crate::must_use(Rc::clone(&counter)).boxed();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            boxed
+                .map_in_place(|rc: Rc<()>| -> Rc<()> {
+                    drop(rc);
+                    panic!("transition failed")
+                })
+                .destroy();
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
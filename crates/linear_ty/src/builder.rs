@@ -0,0 +1,151 @@
+/// Type level flag of a builder field that is set.
+pub struct Set;
+
+/// Type level flag of a builder field that is not set yet.
+pub struct Unset;
+
+/// Generates a struct along with a linear typestate builder. Every field is required, setting
+/// it flips its type level flag and `build()` only exists once all flags are set. The builder
+/// is linear, abandoning a half configured builder is a linearity violation.
+///
+/// The struct is followed by the declaration of the builder type.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// builder! {
+///     /// Server configuration.
+///     pub struct Config {
+///         pub host: String,
+///         pub port: u16,
+///     }
+///     /// Builder of `Config`.
+///     pub struct ConfigBuilder;
+/// }
+///
+/// let config = Config::builder()
+///     .port(8080)
+///     .host(String::from("localhost"))
+///     .build();
+/// assert_eq!(config.port, 8080);
+/// ```
+///
+/// A builder with missing fields can not be built:
+///
+/// ```compile_fail
+/// use linear_ty::*;
+///
+/// builder! {
+///     pub struct Config {
+///         pub host: String,
+///         pub port: u16,
+///     }
+///     pub struct ConfigBuilder;
+/// }
+///
+/// let config = Config::builder().port(8080).build();
+/// ```
+#[macro_export]
+macro_rules! builder {
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis struct $name:ident {
+            $($(#[$($fmeta:tt)*])* $fvis:vis $field:ident: $ty:ty),* $(,)?
+        }
+        $(#[$($bmeta:tt)*])*
+        $bvis:vis struct $builder:ident;
+    ) => {
+        $(#[$($meta)*])*
+        $vis struct $name {
+            $($(#[$($fmeta)*])* $fvis $field: $ty),*
+        }
+
+        $(#[$($bmeta)*])*
+        #[must_use]
+        #[allow(non_camel_case_types)]
+        $bvis struct $builder<$($field),*>(
+            $crate::Linear<($(::core::option::Option<$ty>,)*), ($($field,)*)>,
+        );
+
+        impl $name {
+            #[doc = concat!("Creates a [`", stringify!($builder), "`] with all fields unset.")]
+            pub const fn builder() -> $builder<$($crate::builder!(@flag $field $crate::builder::Unset)),*> {
+                $builder($crate::__linear_from_parts(($($crate::builder!(@none $field),)*)))
+            }
+        }
+
+        impl $builder<$($crate::builder!(@flag $field $crate::builder::Set)),*> {
+            #[doc = concat!("Builds the [`", stringify!($name), "`] once all fields are set.")]
+            pub fn build(self) -> $name {
+                let ($($field,)*) = self.0.into();
+                $name {
+                    $($field: $field.unwrap_or_else(|| unreachable!("all fields are set")),)*
+                }
+            }
+        }
+
+        $crate::builder!(@setters $builder [] $($field: $ty,)*);
+    };
+    (@flag $field:ident $flag:ty) => {
+        $flag
+    };
+    (@none $field:ident) => {
+        ::core::option::Option::None
+    };
+    (@setters $builder:ident [$($before:ident)*]) => {};
+    (
+        @setters $builder:ident [$($before:ident)*]
+        $field:ident: $ty:ty, $($after:ident: $aty:ty,)*
+    ) => {
+        #[allow(non_camel_case_types)]
+        impl<$($before,)* $($after,)*> $builder<$($before,)* $crate::builder::Unset, $($after,)*> {
+            #[doc = concat!("Sets the `", stringify!($field), "` field.")]
+            pub fn $field(
+                self,
+                value: $ty,
+            ) -> $builder<$($before,)* $crate::builder::Set, $($after,)*> {
+                let ($($before,)* _, $($after,)*) = self.0.into();
+                $builder($crate::__linear_from_parts((
+                    $($before,)*
+                    ::core::option::Option::Some(value),
+                    $($after,)*
+                )))
+            }
+        }
+
+        $crate::builder!(@setters $builder [$($before)* $field] $($after: $aty,)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    builder! {
+        /// Test request.
+        pub struct Request {
+            method: &'static str,
+            path: String,
+            retries: u8,
+        }
+        /// Builder of `Request`.
+        pub struct RequestBuilder;
+    }
+
+    #[test]
+    fn any_order() {
+        let request = Request::builder()
+            .retries(3)
+            .method("GET")
+            .path(String::from("/"))
+            .build();
+        assert_eq!((request.method, request.path.as_str()), ("GET", "/"));
+        assert_eq!(request.retries, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn abandoned() {
+        let _ = Request::builder().method("GET");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{__linear_from_parts, MustUse};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+/// Outcome of a `CancelToken`, as acknowledged by its `CancelObserver`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelOutcome {
+    /// The holder cancelled the work.
+    Cancelled,
+    /// The holder disarmed the token, the work is not cancelled.
+    Disarmed,
+}
+
+struct Shared {
+    outcome: Mutex<Option<CancelOutcome>>,
+    resolved: Condvar,
+}
+
+/// Linear cancellation token, the holder must either `cancel()` or `disarm()` it. Created along
+/// with a linear `CancelObserver` by `CancelToken::pair()`, which must `acknowledge()` the
+/// outcome. Shutdown paths can neither forget to propagate nor to observe the cancellation.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let (token, observer) = CancelToken::pair();
+/// let worker = std::thread::spawn(move || {
+///     while !observer.is_cancelled() {
+///         std::thread::yield_now();
+///     }
+///     observer.wait()
+/// });
+/// token.cancel();
+/// assert_eq!(worker.join().unwrap(), CancelOutcome::Cancelled);
+/// ```
+#[must_use]
+pub struct CancelToken(MustUse<Arc<Shared>>);
+
+/// Linear observer of a `CancelToken`, it must acknowledge the outcome of the token.
+#[must_use]
+pub struct CancelObserver(MustUse<Arc<Shared>>);
+
+impl CancelToken {
+    /// Creates a token along with its observer.
+    pub fn pair() -> (Self, CancelObserver) {
+        let shared = Arc::new(Shared {
+            outcome: Mutex::new(None),
+            resolved: Condvar::new(),
+        });
+        (
+            Self(__linear_from_parts(Arc::clone(&shared))),
+            CancelObserver(__linear_from_parts(shared)),
+        )
+    }
+
+    /// Cancels the work of the observer.
+    pub fn cancel(self) {
+        self.resolve(CancelOutcome::Cancelled);
+    }
+
+    /// Disarms the token, the work of the observer is not cancelled.
+    pub fn disarm(self) {
+        self.resolve(CancelOutcome::Disarmed);
+    }
+
+    fn resolve(self, outcome: CancelOutcome) {
+        let shared = self.0.into();
+        *shared.outcome() = Some(outcome);
+        shared.resolved.notify_all();
+    }
+}
+
+impl CancelObserver {
+    /// Returns whether the token was cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.0.outcome() == Some(CancelOutcome::Cancelled)
+    }
+
+    /// Acknowledges the outcome, or returns the observer back while the token is unresolved.
+    ///
+    /// # Errors
+    ///
+    /// When the token is neither cancelled nor disarmed yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (token, observer) = CancelToken::pair();
+    /// let observer = observer.acknowledge().err().unwrap();
+    /// token.disarm();
+    /// assert_eq!(observer.acknowledge().ok(), Some(CancelOutcome::Disarmed));
+    /// ```
+    pub fn acknowledge(self) -> Result<CancelOutcome, Self> {
+        let outcome = *self.0.0.outcome();
+        match outcome {
+            Some(outcome) => {
+                self.0.destroy();
+                Ok(outcome)
+            }
+            None => Err(self),
+        }
+    }
+
+    /// Blocks until the token is resolved and acknowledges the outcome.
+    pub fn wait(self) -> CancelOutcome {
+        let shared = self.0.into();
+        let mut outcome = shared.outcome();
+        loop {
+            if let Some(outcome) = *outcome {
+                return outcome;
+            }
+            outcome = shared
+                .resolved
+                .wait(outcome)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+impl Shared {
+    fn outcome(&self) -> MutexGuard<'_, Option<CancelOutcome>> {
+        // The outcome is only ever written once, a poisoned lock holds a valid outcome.
+        self.outcome.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CancelOutcome, CancelToken};
+
+    #[test]
+    fn disarmed() {
+        let (token, observer) = CancelToken::pair();
+        let handle = std::thread::spawn(move || observer.wait());
+        token.disarm();
+        assert_eq!(handle.join().unwrap(), CancelOutcome::Disarmed);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn unacknowledged() {
+        let (token, _) = CancelToken::pair();
+        token.cancel();
+    }
+}
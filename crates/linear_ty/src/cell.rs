@@ -0,0 +1,131 @@
+use crate::{__linear_from_parts, MustUse};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A slot which must be filled exactly once and taken exactly once. The slot is only reachable
+/// through the linear `Setter` and `Getter` tokens created along with it by
+/// `LinearCell::handoff()`, thus a producer/consumer handoff through the slot is balanced.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// fn on_done(callback: impl FnOnce(u32)) {
+///     callback(42);
+/// }
+///
+/// let (setter, getter) = LinearCell::handoff();
+/// on_done(|result| setter.set(result));
+/// assert_eq!(getter.take().ok(), Some(42));
+/// ```
+pub struct LinearCell<T> {
+    slot: Mutex<Option<T>>,
+}
+
+/// Linear token filling the slot of a `LinearCell`.
+#[must_use]
+pub struct Setter<T>(MustUse<Arc<LinearCell<T>>>);
+
+/// Linear token taking the value out of the slot of a `LinearCell`.
+#[must_use]
+pub struct Getter<T>(MustUse<Arc<LinearCell<T>>>);
+
+impl<T> LinearCell<T> {
+    /// Creates an empty slot and returns the tokens to fill it and to take its value.
+    pub fn handoff() -> (Setter<T>, Getter<T>) {
+        let cell = Arc::new(Self {
+            slot: Mutex::new(None),
+        });
+        (
+            Setter(__linear_from_parts(Arc::clone(&cell))),
+            Getter(__linear_from_parts(cell)),
+        )
+    }
+
+    fn slot(&self) -> std::sync::MutexGuard<'_, Option<T>> {
+        // The slot is only locked to move a value in or out, a poisoned lock holds a valid slot.
+        self.slot.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Setter<T> {
+    /// Fills the slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (setter, getter) = LinearCell::handoff();
+    /// std::thread::spawn(move || setter.set("done")).join().unwrap();
+    /// assert_eq!(getter.take().ok(), Some("done"));
+    /// ```
+    pub fn set(self, value: T) {
+        *self.0.into().slot() = Some(value);
+    }
+}
+
+impl<T> Getter<T> {
+    /// Returns whether the slot is filled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (setter, getter) = LinearCell::handoff();
+    /// assert!(!getter.is_set());
+    /// setter.set(1);
+    /// assert!(getter.is_set());
+    /// # getter.take().ok().unwrap();
+    /// ```
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        self.0.0.slot().is_some()
+    }
+
+    /// Takes the value out of the slot, or returns the getter back when the slot is not filled
+    /// yet.
+    ///
+    /// # Errors
+    ///
+    /// When the slot is not filled yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (setter, getter) = LinearCell::handoff();
+    /// let getter = getter.take().err().unwrap();
+    /// setter.set(1);
+    /// assert_eq!(getter.take().ok(), Some(1));
+    /// ```
+    pub fn take(self) -> Result<T, Self> {
+        let value = self.0.0.slot().take();
+        match value {
+            Some(value) => {
+                self.0.destroy();
+                Ok(value)
+            }
+            None => Err(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearCell;
+
+    #[test]
+    fn handoff() {
+        let (setter, getter) = LinearCell::handoff();
+        let getter = getter.take().err().unwrap();
+        let handle = std::thread::spawn(move || setter.set(String::from("value")));
+        handle.join().unwrap();
+        assert_eq!(getter.take().ok().unwrap(), "value");
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn setter_dropped() {
+        let (_, getter) = LinearCell::<()>::handoff();
+        getter.take().err().unwrap().0.destroy();
+    }
+}
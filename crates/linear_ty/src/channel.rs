@@ -0,0 +1,292 @@
+use crate::{__linear_from_parts, Linear, MustUse};
+use std::sync::mpsc;
+
+/// Message of a oneshot channel, the value or the reason the sender closed the channel.
+type Oneshot<T> = Result<T, &'static str>;
+
+/// Linear sending half of a oneshot channel, it must send a value or be explicitly closed with
+/// a reason.
+#[must_use]
+pub struct OneshotSender<T>(MustUse<mpsc::SyncSender<Oneshot<T>>>);
+
+/// Linear receiving half of a oneshot channel, it must receive or be explicitly cancelled.
+#[must_use]
+pub struct OneshotReceiver<T>(MustUse<mpsc::Receiver<Oneshot<T>>>);
+
+/// Error of a oneshot receive, the sender closed the channel without sending a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed {
+    /// Reason given by the sender.
+    pub reason: &'static str,
+}
+
+impl core::fmt::Display for Closed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "channel closed: {}", self.reason)
+    }
+}
+
+impl std::error::Error for Closed {}
+
+/// Creates a oneshot channel, exactly one value is sent or the channel is closed with a
+/// reason.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::channel;
+///
+/// let (tx, rx) = channel::oneshot();
+/// std::thread::spawn(move || tx.send("done").ok().unwrap());
+/// assert_eq!(rx.recv(), Ok("done"));
+///
+/// let (tx, rx) = channel::oneshot::<()>();
+/// tx.close("request cancelled");
+/// assert_eq!(rx.recv().unwrap_err().reason, "request cancelled");
+/// ```
+pub fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let (tx, rx) = mpsc::sync_channel(1);
+    (
+        OneshotSender(__linear_from_parts(tx)),
+        OneshotReceiver(__linear_from_parts(rx)),
+    )
+}
+
+impl<T> OneshotSender<T> {
+    /// Sends the value.
+    ///
+    /// # Errors
+    ///
+    /// When the receiver was cancelled the value is returned.
+    pub fn send(self, value: T) -> Result<(), T> {
+        self.0
+            .into()
+            .send(Ok(value))
+            .map_err(|mpsc::SendError(message)| {
+                message.unwrap_or_else(|_| unreachable!("a value was sent"))
+            })
+    }
+
+    /// Closes the channel without sending a value, the receiver gets the reason.
+    pub fn close(self, reason: &'static str) {
+        // A cancelled receiver does not care about the reason.
+        let _ = self.0.into().send(Err(reason));
+    }
+}
+
+impl<T> OneshotReceiver<T> {
+    /// Receives the value, blocks until it is sent or the channel is closed.
+    ///
+    /// # Errors
+    ///
+    /// When the sender closed the channel, or leaked it, without sending a value.
+    pub fn recv(self) -> Result<T, Closed> {
+        match self.0.into().recv() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(reason)) => Err(Closed { reason }),
+            Err(mpsc::RecvError) => Err(Closed {
+                reason: "sender leaked",
+            }),
+        }
+    }
+
+    /// Cancels the receive, a value sent later is returned to the sender.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::channel;
+    ///
+    /// let (tx, rx) = channel::oneshot();
+    /// rx.cancel();
+    /// assert_eq!(tx.send(1), Err(1));
+    /// ```
+    pub fn cancel(self) {
+        drop(self.0.into());
+    }
+}
+
+/// Linear sending half of a mpsc channel created by `mpsc()`. Every clone must be explicitly
+/// closed.
+#[must_use]
+pub struct Sender<T, U>(MustUse<mpsc::Sender<T>>, core::marker::PhantomData<fn(U)>);
+
+/// Linear receiving half of a mpsc channel created by `mpsc()`, it yields the messages as
+/// `Linear<T>` values. It can only be finished once all senders are closed and all messages
+/// are received.
+#[must_use]
+pub struct Receiver<T, U> {
+    rx: MustUse<mpsc::Receiver<T>>,
+    peeked: Option<T>,
+    unique: core::marker::PhantomData<fn() -> U>,
+}
+
+/// Creates a mpsc channel of linear messages. Neither dropped messages nor silently dropped
+/// senders go unnoticed.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::{channel, must_use};
+///
+/// let (tx, mut rx) = channel::mpsc();
+/// let worker = tx.clone();
+/// std::thread::spawn(move || {
+///     worker.send(must_use(1)).ok().unwrap();
+///     worker.close();
+/// });
+/// tx.send(must_use(2)).ok().unwrap();
+/// tx.close();
+/// let mut sum = 0;
+/// while let Some(message) = rx.recv() {
+///     sum += message.into();
+/// }
+/// rx.finish().ok().unwrap();
+/// assert_eq!(sum, 3);
+/// ```
+pub fn mpsc<T, U>() -> (Sender<T, U>, Receiver<T, U>) {
+    let (tx, rx) = mpsc::channel();
+    (
+        Sender(__linear_from_parts(tx), core::marker::PhantomData),
+        Receiver {
+            rx: __linear_from_parts(rx),
+            peeked: None,
+            unique: core::marker::PhantomData,
+        },
+    )
+}
+
+impl<T, U> Sender<T, U> {
+    /// Sends a linear message.
+    ///
+    /// # Errors
+    ///
+    /// When the receiver is gone the message is returned.
+    pub fn send(&self, message: Linear<T, U>) -> Result<(), Linear<T, U>> {
+        self.0
+            .0
+            .send(message.into())
+            .map_err(|mpsc::SendError(message)| __linear_from_parts(message))
+    }
+
+    /// Closes this sender.
+    pub fn close(self) {
+        drop(self.0.into());
+    }
+}
+
+/// Every clone is linear on its own.
+impl<T, U> Clone for Sender<T, U> {
+    fn clone(&self) -> Self {
+        Self(
+            __linear_from_parts(mpsc::Sender::clone(&self.0.0)),
+            core::marker::PhantomData,
+        )
+    }
+}
+
+impl<T, U> Receiver<T, U> {
+    /// Receives a message, blocks until one is sent. Returns `None` once all senders are
+    /// closed and all messages are received.
+    pub fn recv(&mut self) -> Option<Linear<T, U>> {
+        self.peeked
+            .take()
+            .or_else(|| self.rx.0.recv().ok())
+            .map(__linear_from_parts)
+    }
+
+    /// Receives a message without blocking.
+    pub fn try_recv(&mut self) -> Option<Linear<T, U>> {
+        self.peeked
+            .take()
+            .or_else(|| self.rx.0.try_recv().ok())
+            .map(__linear_from_parts)
+    }
+
+    /// Finishes the receiver once all senders are closed and all messages are received.
+    ///
+    /// # Errors
+    ///
+    /// When senders are open or messages are left the receiver is returned as `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::{channel, must_use};
+    ///
+    /// let (tx, rx) = channel::mpsc();
+    /// tx.send(must_use("message")).ok().unwrap();
+    /// tx.close();
+    /// let mut rx = rx.finish().err().unwrap();
+    /// rx.recv().unwrap().destroy();
+    /// rx.finish().ok().unwrap();
+    /// ```
+    pub fn finish(mut self) -> Result<(), Self> {
+        if self.peeked.is_some() {
+            return Err(self);
+        }
+        match self.rx.0.try_recv() {
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.rx.destroy();
+                Ok(())
+            }
+            Err(mpsc::TryRecvError::Empty) => Err(self),
+            Ok(message) => {
+                self.peeked = Some(message);
+                Err(self)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mpsc, oneshot};
+    use crate::must_use;
+
+    #[test]
+    fn oneshot_sent() {
+        let (tx, rx) = oneshot();
+        tx.send(String::from("value")).ok().unwrap();
+        assert_eq!(rx.recv().unwrap(), "value");
+        let (tx, rx) = oneshot::<u8>();
+        rx.cancel();
+        tx.close("not needed");
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn oneshot_sender_dropped() {
+        let (_, rx) = oneshot::<u8>();
+        rx.cancel();
+    }
+
+    #[test]
+    fn mpsc_received() {
+        let (tx, mut rx) = mpsc();
+        for i in 0..3 {
+            let tx = tx.clone();
+            std::thread::spawn(move || {
+                tx.send(must_use(i)).ok().unwrap();
+                tx.close();
+            });
+        }
+        tx.close();
+        let mut received: Vec<_> = core::iter::from_fn(|| rx.recv())
+            .map(crate::Linear::into)
+            .collect();
+        received.sort_unstable();
+        assert_eq!(received, [0, 1, 2]);
+        assert!(rx.try_recv().is_none());
+        rx.finish().ok().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn mpsc_message_dropped() {
+        let (tx, mut rx) = mpsc();
+        tx.send(must_use(1)).ok().unwrap();
+        tx.close();
+        let _ = rx.recv();
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// Largest number of credits `spend()` is implemented for.
+pub const MAX_CREDITS: usize = 64;
+
+/// A linear token which must be spent exactly `N` times. Each `spend()` returns the token with
+/// one credit less, only `Credits<0>` can be finished. Useful to enforce that a retry budget or
+/// a batch of exactly `N` acknowledgements is fully used.
+///
+/// Stable Rust can not compute `N - 1` of a generic const, thus `spend()` is implemented for
+/// each `N` up to [`MAX_CREDITS`].
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let credits = Credits::<2>::new();
+/// assert_eq!(credits.remaining(), 2);
+/// let credits = credits.spend().spend();
+/// credits.finish();
+/// ```
+///
+/// Unspent credits can not be finished:
+///
+/// ```compile_fail
+/// # use linear_ty::*;
+/// Credits::<2>::new().spend().finish();
+/// ```
+#[must_use]
+pub struct Credits<const N: usize>(MustUse<()>);
+
+impl<const N: usize> Credits<N> {
+    /// Creates a token holding `N` credits.
+    pub const fn new() -> Self {
+        Self(__linear_from_parts(()))
+    }
+
+    /// Returns the number of credits left to spend.
+    pub const fn remaining(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for Credits<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Credits<0> {
+    /// Finishes the token once all credits are spent.
+    pub fn finish(self) {
+        self.0.destroy();
+    }
+}
+
+macro_rules! impl_spend {
+    ($($less:literal $n:literal,)*) => {
+        $(
+            impl Credits<$n> {
+                /// Spends one credit.
+                pub fn spend(self) -> Credits<$less> {
+                    self.0.destroy();
+                    Credits::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_spend!(
+    0 1, 1 2, 2 3, 3 4, 4 5, 5 6, 6 7, 7 8,
+    8 9, 9 10, 10 11, 11 12, 12 13, 13 14, 14 15, 15 16,
+    16 17, 17 18, 18 19, 19 20, 20 21, 21 22, 22 23, 23 24,
+    24 25, 25 26, 26 27, 27 28, 28 29, 29 30, 30 31, 31 32,
+    32 33, 33 34, 34 35, 35 36, 36 37, 37 38, 38 39, 39 40,
+    40 41, 41 42, 42 43, 43 44, 44 45, 45 46, 46 47, 47 48,
+    48 49, 49 50, 50 51, 51 52, 52 53, 53 54, 54 55, 55 56,
+    56 57, 57 58, 58 59, 59 60, 60 61, 61 62, 62 63, 63 64,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Credits;
+
+    #[test]
+    fn spent() {
+        let credits = Credits::<3>::new().spend();
+        assert_eq!(credits.remaining(), 2);
+        credits.spend().spend().finish();
+    }
+
+    #[test]
+    fn max() {
+        let mut count = 0;
+        let credits = Credits::<{ super::MAX_CREDITS }>::new();
+        // unrolling is the only way to spend credits one by one
+        macro_rules! spend8 {
+            ($credits:expr) => {{
+                count += 8;
+                $credits
+                    .spend()
+                    .spend()
+                    .spend()
+                    .spend()
+                    .spend()
+                    .spend()
+                    .spend()
+                    .spend()
+            }};
+        }
+        let credits = spend8!(spend8!(spend8!(spend8!(credits))));
+        let credits = spend8!(spend8!(spend8!(spend8!(credits))));
+        credits.finish();
+        assert_eq!(count, 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn unspent() {
+        let _ = Credits::<1>::new();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{__linear_from_parts, MustUse};
+use core::ffi::c_int;
+
+/// A raw handle from a C library along with its destructor. The handle must be consumed by
+/// `destroy()`, which returns the return code of the destructor instead of losing it in a
+/// `Drop` implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::ffi::ForeignHandle;
+/// use std::ffi::c_int;
+///
+/// // stands in for e.g. `sqlite3_close()`
+/// fn db_close(db: *mut u8) -> c_int {
+///     if db.is_null() { 21 } else { 0 }
+/// }
+///
+/// let mut db = 0u8;
+/// let handle = ForeignHandle::new(&raw mut db, db_close);
+/// assert!(!handle.get().is_null());
+/// assert_eq!(handle.destroy(), 0);
+/// ```
+#[must_use]
+pub struct ForeignHandle<T, D: FnOnce(T) -> c_int>(MustUse<(T, D)>);
+
+impl<T, D: FnOnce(T) -> c_int> ForeignHandle<T, D> {
+    /// Wraps a raw handle along with the destructor which must release it.
+    pub const fn new(handle: T, destructor: D) -> Self {
+        Self(__linear_from_parts((handle, destructor)))
+    }
+
+    /// Returns a reference to the raw handle, for passing it to the library.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.0.0.0
+    }
+
+    /// Releases the handle with its destructor and returns the return code.
+    pub fn destroy(self) -> c_int {
+        let (handle, destructor) = self.0.into();
+        destructor(handle)
+    }
+
+    /// Returns the raw handle without calling the destructor, releasing it becomes the duty
+    /// of the caller.
+    pub fn into_raw(self) -> T {
+        self.0.into().0
+    }
+}
+
+impl<T: core::fmt::Debug, D: FnOnce(T) -> c_int> core::fmt::Debug for ForeignHandle<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ForeignHandle").field(self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForeignHandle;
+    use core::cell::Cell;
+
+    #[test]
+    fn destructor_code() {
+        let closed = Cell::new(None);
+        let handle = ForeignHandle::new(3, |fd| {
+            closed.set(Some(fd));
+            -1
+        });
+        assert_eq!(handle.destroy(), -1);
+        assert_eq!(closed.get(), Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = ForeignHandle::new((), |()| 0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::Linear;
+
+/// A linear value bundled with the consumer registered by `Linear::finally()`. The finalizer is
+/// linear itself, it must be either `run()` or cancelled with `cancel_into_inner()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// fn release(finalizer: Finalizer<u32, impl Sized, impl FnOnce(u32) -> u32>) -> u32 {
+///     finalizer.run()
+/// }
+///
+/// let finalizer = new_linear!(20).finally(|x| x + 22);
+/// assert_eq!(release(finalizer), 42);
+/// ```
+#[must_use]
+pub struct Finalizer<T, U, F> {
+    linear: Linear<T, U>,
+    f: F,
+}
+
+/// Additional `finally()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Registers the consumer which will consume the value later. This sets up a contract that
+    /// can be passed across function boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let finalizer = new_linear!("text").finally(str::len);
+    /// assert_eq!(finalizer.run(), 4);
+    /// ```
+    pub const fn finally<F: FnOnce(T) -> R, R>(self, f: F) -> Finalizer<T, U, F> {
+        Finalizer { linear: self, f }
+    }
+}
+
+impl<T, U, F: FnOnce(T) -> R, R> Finalizer<T, U, F> {
+    /// Consumes the value with the registered consumer and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut log = Vec::new();
+    /// new_linear!("closed").finally(|x| log.push(x)).run();
+    /// assert_eq!(log, ["closed"]);
+    /// ```
+    pub fn run(self) -> R {
+        self.linear.consume(self.f)
+    }
+
+    /// Cancels the registered consumer without calling it and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let finalizer = new_linear!(1).finally(|_| unreachable!());
+    /// assert_eq!(finalizer.cancel_into_inner(), 1);
+    /// ```
+    pub fn cancel_into_inner(self) -> T {
+        self.linear.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = crate::new_linear!(1).finally(drop);
+    }
+}
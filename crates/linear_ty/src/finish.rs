@@ -0,0 +1,156 @@
+use crate::{__linear_from_parts, MustUse};
+use std::io::{self, Write};
+
+/// Streaming encoders and writers completed by a fallible `finish()`, which e.g. writes the
+/// trailer of a compressed stream.
+pub trait Finish {
+    /// Returned on success, usually the underlying writer.
+    type Output;
+    /// Returned on failure.
+    type Error;
+
+    /// Completes the stream.
+    ///
+    /// # Errors
+    ///
+    /// When completing the stream fails.
+    fn finish(self) -> Result<Self::Output, Self::Error>;
+}
+
+/// An encoder which must be finished. Forgetting `finish()` can not silently truncate the
+/// output, e.g. by leaving out the trailer of a compressed stream. Buffered writers are
+/// covered by `io::LinearBufWriter` which returns the writer even when flushing fails.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// /// Encoder terminating its output with a newline.
+/// struct Terminated(Vec<u8>);
+///
+/// impl Finish for Terminated {
+///     type Output = Vec<u8>;
+///     type Error = std::io::Error;
+///
+///     fn finish(mut self) -> std::io::Result<Vec<u8>> {
+///         self.0.push(b'\n');
+///         Ok(self.0)
+///     }
+/// }
+///
+/// let mut encoder = MustFinish::new(Terminated(Vec::new()));
+/// encoder.get_mut().0.extend_from_slice(b"payload");
+/// assert_eq!(encoder.finish()?, b"payload\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct MustFinish<E: Finish>(MustUse<E>);
+
+impl<E: Finish> MustFinish<E> {
+    /// Wraps an encoder which must be finished.
+    pub const fn new(encoder: E) -> Self {
+        Self(__linear_from_parts(encoder))
+    }
+
+    /// Returns a reference to the encoder.
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        &self.0.0
+    }
+
+    /// Returns a mutable reference to the encoder.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut E {
+        &mut self.0.0
+    }
+
+    /// Finishes the encoder.
+    ///
+    /// # Errors
+    ///
+    /// When finishing the encoder fails.
+    pub fn finish(self) -> Result<E::Output, E::Error> {
+        self.0.into().finish()
+    }
+}
+
+impl<E: Finish + Write> Write for MustFinish<E> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+/// Implements `Finish` for writing encoders with an inherent `finish() -> io::Result<W>`.
+#[cfg(feature = "flate2")]
+macro_rules! impl_finish {
+    ($($encoder:ty),+) => {
+        $(
+            impl<W: Write> Finish for $encoder {
+                type Output = W;
+                type Error = io::Error;
+
+                fn finish(self) -> io::Result<W> {
+                    <$encoder>::finish(self)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "flate2")]
+impl_finish!(
+    flate2::write::GzEncoder<W>,
+    flate2::write::ZlibEncoder<W>,
+    flate2::write::DeflateEncoder<W>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{Finish, MustFinish};
+    use std::io;
+
+    /// Encoder failing to write its trailer.
+    struct Failing;
+
+    impl Finish for Failing {
+        type Output = ();
+        type Error = io::Error;
+
+        fn finish(self) -> io::Result<()> {
+            Err(io::ErrorKind::WriteZero.into())
+        }
+    }
+
+    #[test]
+    fn finish_failed() {
+        let error = MustFinish::new(Failing).finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn unfinished() {
+        let _ = MustFinish::new(Failing);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn gzip_trailer() {
+        use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+        use std::io::{Read, Write};
+
+        let mut encoder = MustFinish::new(GzEncoder::new(Vec::new(), Compression::default()));
+        encoder.write_all(b"compressed").unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut text = String::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "compressed");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// `FnOnce` closures called with their arguments as a tuple. Implemented for closures of up to
+/// six arguments, stable Rust can not abstract over the arguments of closures otherwise.
+pub trait CallOnce<Args> {
+    /// Return type of the closure.
+    type Output;
+
+    /// Calls the closure.
+    fn call_once(self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_call_once {
+    ($(($($a:ident $v:ident),*))+) => {
+        $(
+            impl<F: FnOnce($($a),*) -> R, R, $($a),*> CallOnce<($($a,)*)> for F {
+                type Output = R;
+
+                fn call_once(self, ($($v,)*): ($($a,)*)) -> R {
+                    self($($v),*)
+                }
+            }
+        )+
+    };
+}
+
+impl_call_once! {
+    ()
+    (A1 a1)
+    (A1 a1, A2 a2)
+    (A1 a1, A2 a2, A3 a3)
+    (A1 a1, A2 a2, A3 a3, A4 a4)
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5)
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6)
+}
+
+/// A closure which must be called exactly once, dropping it without calling it is a linearity
+/// violation. Callback registries can not forget to fire their callbacks.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut fired = Vec::new();
+/// let callbacks: Vec<LinearFnOnce<Box<dyn FnOnce(u32) -> u32>>> = vec![
+///     LinearFnOnce::new(Box::new(|x| x + 1)),
+///     LinearFnOnce::new(Box::new(|x| x * 2)),
+/// ];
+/// for callback in callbacks {
+///     fired.push(callback.call((10,)));
+/// }
+/// assert_eq!(fired, [11, 20]);
+/// ```
+#[must_use]
+pub struct LinearFnOnce<F>(MustUse<F>);
+
+impl<F> LinearFnOnce<F> {
+    /// Wraps a closure which must be called exactly once.
+    pub const fn new(f: F) -> Self {
+        Self(__linear_from_parts(f))
+    }
+
+    /// Calls the closure with its arguments as a tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let add = LinearFnOnce::new(|a: i32, b: i32| a + b);
+    /// assert_eq!(add.call((1, 2)), 3);
+    /// ```
+    pub fn call<Args>(self, args: Args) -> F::Output
+    where
+        F: CallOnce<Args>,
+    {
+        self.0.into().call_once(args)
+    }
+
+    /// Returns the closure without calling it, it is not linear anymore.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let callback = LinearFnOnce::new(|| 1).into_inner();
+    /// drop(callback);
+    /// ```
+    pub fn into_inner(self) -> F {
+        self.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearFnOnce;
+
+    #[test]
+    fn called() {
+        let mut count = 0;
+        LinearFnOnce::new(|| count += 1).call(());
+        let text = String::from("moved");
+        let moved = LinearFnOnce::new(move |suffix: &str| text + suffix).call(("!",));
+        assert_eq!((count, moved.as_str()), (1, "moved!"));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearFnOnce::new(|| ());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{__linear_from_parts, __linearity, Linear, Linearity};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Awaiting a `Linear<F>` consumes the linearity and awaits the inner future.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// let answer = new_linear!(async { 42 });
+/// let mut task = pin!(async move { answer.await });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(42));
+/// ```
+impl<F: Future, U> IntoFuture for Linear<F, U> {
+    type Output = F::Output;
+    type IntoFuture = F;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.into()
+    }
+}
+
+/// Additional `map_async()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Transforms the inner value with an async closure. The returned future is a
+    /// `LinearFuture`, it must be driven to completion and yields the linear result. Async
+    /// state transitions chain like synchronous `map()` calls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// let mut task = pin!(async {
+    ///     let connected = new_linear!("host").map_async(|host| async move { host.len() }).await;
+    ///     let sent = connected.map_async(|len| async move { len * 2 }).await;
+    ///     sent.into()
+    /// });
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(8));
+    /// ```
+    pub fn map_async<F, Fut>(
+        self,
+        f: F,
+    ) -> LinearFuture<impl Future<Output = Linear<Fut::Output, Self>>>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future,
+    {
+        let inner = self.into();
+        LinearFuture::new(async move { __linear_from_parts(f(inner).await) })
+    }
+}
+
+/// A future which must be driven to completion. Dropping it before it yielded
+/// `Poll::Ready` is a linearity violation, e.g. when it is cancelled by losing a `select!`.
+/// `into_inner_unpolled()` is the explicit cancellation path.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// let work = LinearFuture::new(async { 42 });
+/// let mut task = pin!(async move { work.await + 1 });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(43));
+/// ```
+#[must_use = "futures do nothing unless awaited"]
+pub struct LinearFuture<F> {
+    future: F,
+    linearity: Option<Linearity<F>>,
+}
+
+impl<F> LinearFuture<F> {
+    /// Wraps a future which must be driven to completion.
+    pub const fn new(future: F) -> Self {
+        Self {
+            future,
+            linearity: Some(__linearity::<F>()),
+        }
+    }
+
+    /// Cancels the future and returns it. A future which is not `Unpin` can only be moved out
+    /// before it is pinned, thus before it was polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let work = LinearFuture::new(async { 42 });
+    /// drop(work.into_inner_unpolled());
+    /// ```
+    pub fn into_inner_unpolled(mut self) -> F {
+        core::mem::forget(self.linearity.take());
+        self.future
+    }
+}
+
+impl<F: Future> Future for LinearFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: `future` is structurally pinned, it is never moved out of a pinned
+        // `LinearFuture`, which has no `Drop` implementation either.
+        let this = unsafe { self.get_unchecked_mut() };
+        let poll = unsafe { Pin::new_unchecked(&mut this.future) }.poll(cx);
+        if poll.is_ready() {
+            core::mem::forget(this.linearity.take());
+        }
+        poll
+    }
+}
+
+/// Invariant lifetime brand, ties tasks to the `linear_scope_async()` they were spawned in.
+type Brand<'s> = core::marker::PhantomData<fn(&'s ()) -> &'s ()>;
+
+/// Scope of `linear_scope_async()` spawning linear tasks.
+#[derive(Clone, Copy)]
+pub struct AsyncScope<'s>(Brand<'s>);
+
+/// Linear task spawned in a `linear_scope_async()`. It can not escape the scope and must be
+/// awaited inside it, tasks run while they are awaited.
+#[must_use = "futures do nothing unless awaited"]
+pub struct ScopedTask<'s, F> {
+    future: LinearFuture<F>,
+    brand: Brand<'s>,
+}
+
+/// Runs an async closure with a scope spawning linear tasks. Every task must be awaited
+/// inside the scope. The closure returns its body boxed, the body borrows the scope.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use linear_ty::future::linear_scope_async;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+///
+/// let mut task = pin!(linear_scope_async(|s| {
+///     Box::pin(async move {
+///         let a = s.spawn(async { 1 });
+///         let b = s.spawn(async { 2 });
+///         a.await + b.await
+///     })
+/// }));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(3));
+/// ```
+pub async fn linear_scope_async<F, R>(f: F) -> R
+where
+    F: for<'s> FnOnce(AsyncScope<'s>) -> Pin<Box<dyn Future<Output = R> + 's>>,
+{
+    f(AsyncScope(core::marker::PhantomData)).await
+}
+
+impl<'s> AsyncScope<'s> {
+    /// Spawns a task in the scope and returns it.
+    pub const fn spawn<F: Future + 's>(self, future: F) -> ScopedTask<'s, F> {
+        ScopedTask {
+            future: LinearFuture::new(future),
+            brand: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<F: Future> Future for ScopedTask<'_, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: `future` is structurally pinned, it is never moved out of a pinned
+        // `ScopedTask`, which has no `Drop` implementation either.
+        unsafe { self.map_unchecked_mut(|task| &mut task.future) }.poll(cx)
+    }
+}
+
+/// Result of `select2()`, the output of the winner along with the loser.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Either<L, R> {
+    /// The left future completed first.
+    Left(L),
+    /// The right future completed first.
+    Right(R),
+}
+
+/// Future returned by `select2()`.
+#[must_use = "futures do nothing unless awaited"]
+pub struct Select2<A, B>(Option<(LinearFuture<A>, LinearFuture<B>)>);
+
+/// Races two linear futures. The output of the one completing first is returned along with
+/// the loser, which is still a `LinearFuture` and must be driven to completion or cancelled
+/// explicitly. Both futures must be `Unpin` to be handed back, use `Box::pin()` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use linear_ty::future::{Either, select2};
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+///
+/// let mut task = pin!(async {
+///     let fast = LinearFuture::new(Box::pin(async { 1 }));
+///     let slow = LinearFuture::new(Box::pin(async { 2 }));
+///     match select2(fast, slow).await {
+///         Either::Left((first, slow)) => first + slow.await,
+///         Either::Right((first, fast)) => first + fast.await,
+///     }
+/// });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(3));
+/// ```
+pub const fn select2<A, B>(a: LinearFuture<A>, b: LinearFuture<B>) -> Select2<A, B>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    Select2(Some((a, b)))
+}
+
+impl<A, B> Future for Select2<A, B>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    type Output = Either<(A::Output, LinearFuture<B>), (B::Output, LinearFuture<A>)>;
+
+    /// # Panics
+    ///
+    /// When polled after completion.
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (a, b) = self.0.as_mut().expect("select2 polled after completion");
+        if let Poll::Ready(output) = Pin::new(a).poll(cx) {
+            let (a, b) = self.0.take().unwrap_or_else(|| unreachable!());
+            a.into_inner_unpolled();
+            return Poll::Ready(Either::Left((output, b)));
+        }
+        if let Poll::Ready(output) = Pin::new(b).poll(cx) {
+            let (a, b) = self.0.take().unwrap_or_else(|| unreachable!());
+            b.into_inner_unpolled();
+            return Poll::Ready(Either::Right((output, a)));
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Either, LinearFuture, select2};
+    use core::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[test]
+    fn completed() {
+        let mut future = pin!(LinearFuture::new(async { "done" }));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready("done"));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn cancelled() {
+        let mut future = Box::pin(LinearFuture::new(core::future::pending::<()>()));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn map_async_dropped() {
+        drop(crate::new_linear!(1).map_async(|x| async move { x + 1 }));
+    }
+
+    #[test]
+    fn select_loser() {
+        let winner = LinearFuture::new(Box::pin(async { 'a' }));
+        let loser = LinearFuture::new(core::future::pending::<char>());
+        let mut future = pin!(select2(winner, loser));
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(Either::Left(('a', loser))) = future.as_mut().poll(&mut cx) else {
+            panic!("left future must win");
+        };
+        drop(loser.into_inner_unpolled());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// Turns a `Drop` based resource into a linear one with an explicit, usually fallible
+/// finalizer. RAII code migrates to explicit finalization one resource at a time, without a
+/// custom wrapper per type.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use std::io::Write;
+///
+/// let mut log = LinearGuard::new(Vec::new(), |mut log: Vec<u8>| {
+///     log.write_all(b"end")?;
+///     Ok::<_, std::io::Error>(log)
+/// });
+/// log.get_mut().extend_from_slice(b"start ");
+/// assert_eq!(log.finalize()?, b"start end");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+pub struct LinearGuard<T, F: FnOnce(T) -> R, R>(MustUse<(T, F)>);
+
+impl<T, F: FnOnce(T) -> R, R> LinearGuard<T, F, R> {
+    /// Guards a resource with the finalizer which must release it.
+    pub const fn new(value: T, finalizer: F) -> Self {
+        Self(__linear_from_parts((value, finalizer)))
+    }
+
+    /// Returns a reference to the resource.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.0.0.0
+    }
+
+    /// Returns a mutable reference to the resource.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0.0.0
+    }
+
+    /// Runs the finalizer on the resource and returns its result.
+    pub fn finalize(self) -> R {
+        let (value, finalizer) = self.0.into();
+        finalizer(value)
+    }
+
+    /// Returns the resource without running the finalizer, it is released by its `Drop`
+    /// implementation again.
+    pub fn into_inner(self) -> T {
+        self.0.into().0
+    }
+}
+
+impl<T: core::fmt::Debug, F: FnOnce(T) -> R, R> core::fmt::Debug for LinearGuard<T, F, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("LinearGuard").field(self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearGuard;
+
+    #[test]
+    fn finalize_failed() {
+        let guard = LinearGuard::new(String::from("dirty"), |state: String| {
+            if state == "clean" { Ok(()) } else { Err(state) }
+        });
+        assert_eq!(guard.finalize(), Err(String::from("dirty")));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearGuard::new((), |()| ());
+    }
+}
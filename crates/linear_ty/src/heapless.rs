@@ -0,0 +1,175 @@
+use crate::{__linear_from_parts, Linear};
+
+/// A fixed capacity vector of linear elements backed by `heapless::Vec`, the bounded
+/// counterpart of `crate::LinearVec` which does not allocate. It must be drained and
+/// finished with `finish_empty()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use linear_ty::heapless::LinearHeaplessVec;
+///
+/// let mut descriptors = LinearHeaplessVec::<_, _, 2>::new();
+/// descriptors.push(must_use(0x10)).ok().unwrap();
+/// descriptors.push(must_use(0x20)).ok().unwrap();
+/// let full = descriptors.push(must_use(0x30)).unwrap_err();
+/// assert_eq!(full.into(), 0x30);
+/// while let Some(descriptor) = descriptors.pop() {
+///     descriptor.destroy();
+/// }
+/// descriptors.finish_empty().ok().unwrap();
+/// ```
+#[must_use]
+pub struct LinearHeaplessVec<T, U, const N: usize>(Linear<::heapless::Vec<T, N>, U>);
+
+impl<T, U, const N: usize> LinearHeaplessVec<T, U, N> {
+    /// Creates an empty vector.
+    pub const fn new() -> Self {
+        Self(__linear_from_parts(::heapless::Vec::new()))
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.0.len()
+    }
+
+    /// Returns whether the vector has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.0.is_empty()
+    }
+
+    /// Appends a linear element.
+    ///
+    /// # Errors
+    ///
+    /// When the vector is full the element is returned.
+    pub fn push(&mut self, item: Linear<T, U>) -> Result<(), Linear<T, U>> {
+        self.0.0.push(item.into()).map_err(__linear_from_parts)
+    }
+
+    /// Removes the last element.
+    pub fn pop(&mut self) -> Option<Linear<T, U>> {
+        self.0.0.pop().map(__linear_from_parts)
+    }
+
+    /// Finishes an empty vector.
+    ///
+    /// # Errors
+    ///
+    /// When elements are left the vector is returned as `Err`.
+    pub fn finish_empty(self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.0.destroy();
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, U, const N: usize> Default for LinearHeaplessVec<T, U, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A fixed capacity FIFO queue of linear elements backed by `heapless::Deque`. It must be
+/// drained and finished with `finish_empty()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use linear_ty::heapless::LinearQueue;
+///
+/// let mut frames = LinearQueue::<_, _, 4>::new();
+/// frames.enqueue(must_use("frame 1")).ok().unwrap();
+/// frames.enqueue(must_use("frame 2")).ok().unwrap();
+/// assert_eq!(frames.dequeue().unwrap().into(), "frame 1");
+/// assert_eq!(frames.dequeue().unwrap().into(), "frame 2");
+/// frames.finish_empty().ok().unwrap();
+/// ```
+#[must_use]
+pub struct LinearQueue<T, U, const N: usize>(Linear<::heapless::Deque<T, N>, U>);
+
+impl<T, U, const N: usize> LinearQueue<T, U, N> {
+    /// Creates an empty queue.
+    pub const fn new() -> Self {
+        Self(__linear_from_parts(::heapless::Deque::new()))
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.0.len()
+    }
+
+    /// Returns whether the queue has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.0.is_empty()
+    }
+
+    /// Appends a linear element to the back of the queue.
+    ///
+    /// # Errors
+    ///
+    /// When the queue is full the element is returned.
+    pub fn enqueue(&mut self, item: Linear<T, U>) -> Result<(), Linear<T, U>> {
+        self.0.0.push_back(item.into()).map_err(__linear_from_parts)
+    }
+
+    /// Removes the element at the front of the queue.
+    pub fn dequeue(&mut self) -> Option<Linear<T, U>> {
+        self.0.0.pop_front().map(__linear_from_parts)
+    }
+
+    /// Finishes an empty queue.
+    ///
+    /// # Errors
+    ///
+    /// When elements are left the queue is returned as `Err`.
+    pub fn finish_empty(self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.0.destroy();
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, U, const N: usize> Default for LinearQueue<T, U, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearHeaplessVec, LinearQueue};
+    use crate::must_use;
+
+    #[test]
+    fn bounded() {
+        let mut vec = LinearHeaplessVec::<_, _, 1>::new();
+        vec.push(must_use(1)).ok().unwrap();
+        assert_eq!(vec.len(), 1);
+        let mut queue = LinearQueue::<_, _, 1>::new();
+        queue.enqueue(vec.pop().unwrap()).ok().unwrap();
+        queue.enqueue(must_use(2)).unwrap_err().destroy();
+        vec.finish_empty().ok().unwrap();
+        let mut queue = queue.finish_empty().err().unwrap();
+        queue.dequeue().unwrap().destroy();
+        queue.finish_empty().ok().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearQueue::<u8, (), 1>::new();
+    }
+}
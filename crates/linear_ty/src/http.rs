@@ -0,0 +1,204 @@
+use crate::{__linear_from_parts, LinearFuture, MustUse};
+use ::http::response::Parts;
+use ::http::{HeaderMap, Response, StatusCode};
+use bytes::Buf;
+use core::future::{Future, poll_fn};
+use core::pin::pin;
+use http_body::Body;
+
+/// An HTTP response whose body must be consumed. An unconsumed body breaks connection reuse,
+/// thus it must be fully read with `read_all()`, discarded with `drain()` or handed on as a
+/// `LinearBody` by `into_parts()`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::http::ResponseMustUse;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// # struct Full(Option<&'static [u8]>);
+/// # impl http_body::Body for Full {
+/// #     type Data = &'static [u8];
+/// #     type Error = std::convert::Infallible;
+/// #     fn poll_frame(
+/// #         mut self: std::pin::Pin<&mut Self>,
+/// #         _: &mut Context<'_>,
+/// #     ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+/// #         Poll::Ready(self.0.take().map(|data| Ok(http_body::Frame::data(data))))
+/// #     }
+/// # }
+///
+/// let response = ResponseMustUse::new(http::Response::new(Full(Some(b"hello"))));
+/// assert!(response.status().is_success());
+/// let mut task = pin!(response.read_all());
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let Poll::Ready(Ok(response)) = task.as_mut().poll(&mut cx) else {
+///     unreachable!()
+/// };
+/// assert_eq!(response.body(), b"hello");
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct ResponseMustUse<B>(MustUse<Response<B>>);
+
+/// An HTTP body which must be fully read or drained.
+#[must_use]
+#[derive(Debug)]
+pub struct LinearBody<B>(MustUse<B>);
+
+impl<B: Body> ResponseMustUse<B> {
+    /// Wraps a response.
+    pub const fn new(response: Response<B>) -> Self {
+        Self(__linear_from_parts(response))
+    }
+
+    /// Returns the status code of the response.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        self.0.0.status()
+    }
+
+    /// Returns the headers of the response.
+    #[must_use]
+    pub fn headers(&self) -> &HeaderMap {
+        self.0.0.headers()
+    }
+
+    /// Splits the response into its head and its linear body.
+    pub fn into_parts(self) -> (Parts, LinearBody<B>) {
+        let (parts, body) = self.0.into().into_parts();
+        (parts, LinearBody::new(body))
+    }
+
+    /// Reads the whole body and returns the response with the collected body. The returned
+    /// future is a `LinearFuture`, thus a partly read body is not dropped silently.
+    ///
+    /// # Errors
+    ///
+    /// When reading the body fails.
+    pub fn read_all(
+        self,
+    ) -> LinearFuture<impl Future<Output = Result<Response<Vec<u8>>, B::Error>>> {
+        let (parts, body) = self.0.into().into_parts();
+        LinearFuture::new(async move { Ok(Response::from_parts(parts, collect(body).await?)) })
+    }
+
+    /// Reads and discards the whole body, the connection can be reused afterwards. The
+    /// returned future is a `LinearFuture` like the one of `read_all()`.
+    ///
+    /// # Errors
+    ///
+    /// When reading the body fails.
+    pub fn drain(self) -> LinearFuture<impl Future<Output = Result<Parts, B::Error>>> {
+        let (parts, body) = self.0.into().into_parts();
+        LinearFuture::new(async move { for_each_data(body, drop).await.map(|()| parts) })
+    }
+}
+
+impl<B: Body> LinearBody<B> {
+    /// Wraps a body.
+    pub const fn new(body: B) -> Self {
+        Self(__linear_from_parts(body))
+    }
+
+    /// Reads the whole body. Trailers are skipped. The returned future is a `LinearFuture`,
+    /// thus a partly read body is not dropped silently.
+    ///
+    /// # Errors
+    ///
+    /// When reading the body fails.
+    pub fn read_all(self) -> LinearFuture<impl Future<Output = Result<Vec<u8>, B::Error>>> {
+        LinearFuture::new(collect(self.0.into()))
+    }
+
+    /// Reads and discards the whole body. The returned future is a `LinearFuture` like the
+    /// one of `read_all()`.
+    ///
+    /// # Errors
+    ///
+    /// When reading the body fails.
+    pub fn drain(self) -> LinearFuture<impl Future<Output = Result<(), B::Error>>> {
+        LinearFuture::new(for_each_data(self.0.into(), drop))
+    }
+
+    /// Returns the body without reading it, giving up connection reuse unless the caller
+    /// reads it.
+    pub fn into_inner(self) -> B {
+        self.0.into()
+    }
+}
+
+/// Reads the whole body and collects its data.
+async fn collect<B: Body>(body: B) -> Result<Vec<u8>, B::Error> {
+    let mut collected = Vec::new();
+    for_each_data(body, |mut data| {
+        while data.has_remaining() {
+            let chunk = data.chunk();
+            collected.extend_from_slice(chunk);
+            let len = chunk.len();
+            data.advance(len);
+        }
+    })
+    .await?;
+    Ok(collected)
+}
+
+/// Reads the whole body and calls `f` with each data frame, trailers are skipped.
+async fn for_each_data<B: Body>(body: B, mut f: impl FnMut(B::Data)) -> Result<(), B::Error> {
+    let mut body = pin!(body);
+    while let Some(frame) = poll_fn(|cx| body.as_mut().poll_frame(cx)).await {
+        if let Ok(data) = frame?.into_data() {
+            f(data);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseMustUse;
+    use core::future::Future;
+    use core::pin::{Pin, pin};
+    use core::task::{Context, Poll, Waker};
+    use http_body::{Body, Frame};
+
+    struct Chunks(Vec<Result<&'static [u8], &'static str>>);
+
+    impl Body for Chunks {
+        type Data = &'static [u8];
+        type Error = &'static str;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            Poll::Ready(self.0.pop().map(|chunk| chunk.map(Frame::data)))
+        }
+    }
+
+    fn response(chunks: Vec<Result<&'static [u8], &'static str>>) -> ResponseMustUse<Chunks> {
+        ResponseMustUse::new(http::Response::new(Chunks(chunks)))
+    }
+
+    #[test]
+    fn drained() {
+        let (parts, body) = response(vec![Ok(b"b"), Ok(b"a")]).into_parts();
+        assert_eq!(parts.status, http::StatusCode::OK);
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(pin!(body.drain()).poll(&mut cx), Poll::Ready(Ok(())));
+        let read = pin!(response(vec![Err("reset"), Ok(b"a")]).read_all()).poll(&mut cx);
+        assert!(matches!(read, Poll::Ready(Err("reset"))));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = response(Vec::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn read_dropped() {
+        drop(response(vec![Ok(b"a")]).read_all());
+    }
+}
@@ -0,0 +1,539 @@
+use crate::{__linear_from_parts, MustUse};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A file which must be explicitly closed. `Drop` of a `std::fs::File` silently swallows the
+/// errors of closing it, `close()` and `sync_and_close()` return them. `into_std()` opts out.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// use linear_ty::io::LinearFile;
+/// use std::io::{Read, Seek, Write};
+///
+/// let path = std::env::temp_dir().join("linear_ty_file_example");
+/// let mut file = LinearFile::create(&path)?;
+/// file.write_all(b"linear")?;
+/// file.sync_and_close()?;
+///
+/// let mut file = LinearFile::open(&path)?;
+/// let mut content = String::new();
+/// file.read_to_string(&mut content)?;
+/// file.close()?;
+/// assert_eq!(content, "linear");
+/// # std::fs::remove_file(path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearFile(MustUse<File>);
+
+impl LinearFile {
+    /// Wraps an open file.
+    pub const fn new(file: File) -> Self {
+        Self(__linear_from_parts(file))
+    }
+
+    /// Opens a file in read-only mode, like `File::open()`.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be opened.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        File::open(path).map(Self::new)
+    }
+
+    /// Opens a file in write-only mode, creating or truncating it, like `File::create()`.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be created.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        File::create(path).map(Self::new)
+    }
+
+    /// Returns a reference to the underlying file.
+    #[must_use]
+    pub fn get(&self) -> &File {
+        &self.0.0
+    }
+
+    /// Returns a mutable reference to the underlying file.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut File {
+        &mut self.0.0
+    }
+
+    /// Closes the file.
+    ///
+    /// # Errors
+    ///
+    /// When closing fails, e.g. a deferred write error of a network filesystem. Only
+    /// reported on unix, elsewhere the file is dropped.
+    pub fn close(self) -> io::Result<()> {
+        close(self.0.into())
+    }
+
+    /// Syncs all data and metadata to disk and closes the file. The file is closed even when
+    /// syncing fails.
+    ///
+    /// # Errors
+    ///
+    /// When syncing fails, otherwise like `close()`.
+    pub fn sync_and_close(self) -> io::Result<()> {
+        let file = self.0.into();
+        let synced = file.sync_all();
+        let closed = close(file);
+        synced.and(closed)
+    }
+
+    /// Returns the underlying file, which is closed on drop again.
+    pub fn into_std(self) -> File {
+        self.0.into()
+    }
+}
+
+impl Read for LinearFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.get_mut().read(buf)
+    }
+}
+
+impl Write for LinearFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl Seek for LinearFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.get_mut().seek(pos)
+    }
+}
+
+/// A temporary file which must either be `persist()`ed to its final path or `remove()`d, both
+/// return their errors. Temporary files are never leaked or deleted with errors ignored, a
+/// failed `persist()` hands the temporary file back to retry or `remove()` it.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::io::LinearTempFile;
+/// use std::io::Write;
+///
+/// let target = std::env::temp_dir().join("linear_ty_temp_file_example");
+/// let mut file = LinearTempFile::new()?;
+/// file.write_all(b"complete")?;
+/// let file = match file.persist(&target) {
+///     Ok(file) => file,
+///     Err((file, error)) => {
+///         file.remove()?;
+///         return Err(error);
+///     }
+/// };
+/// file.close()?;
+/// assert_eq!(std::fs::read(&target)?, b"complete");
+/// # std::fs::remove_file(target)?;
+///
+/// let scratch = LinearTempFile::new()?;
+/// let path = scratch.path().to_owned();
+/// scratch.remove()?;
+/// assert!(!path.exists());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearTempFile(MustUse<(File, PathBuf)>);
+
+/// A temporary directory which must either be `persist()`ed to its final path or `remove()`d
+/// along with its content, like `LinearTempFile`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::io::LinearTempDir;
+///
+/// let dir = LinearTempDir::new()?;
+/// std::fs::write(dir.path().join("scratch"), b"data")?;
+/// let path = dir.path().to_owned();
+/// dir.remove()?;
+/// assert!(!path.exists());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearTempDir(MustUse<PathBuf>);
+
+impl LinearTempFile {
+    /// Creates a temporary file in `std::env::temp_dir()`.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be created.
+    pub fn new() -> io::Result<Self> {
+        Self::new_in(std::env::temp_dir())
+    }
+
+    /// Creates a temporary file in `dir`. Create it on the filesystem of the final path to
+    /// `persist()` it by an atomic rename.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be created.
+    pub fn new_in(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let (file, path) = create_unique(dir.as_ref(), |path| {
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create_new(true)
+                .open(path)
+        })?;
+        Ok(Self(__linear_from_parts((file, path))))
+    }
+
+    /// Returns the current path of the temporary file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0.0.1
+    }
+
+    /// Returns a reference to the underlying file.
+    #[must_use]
+    pub fn get(&self) -> &File {
+        &self.0.0.0
+    }
+
+    /// Returns a mutable reference to the underlying file.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut File {
+        &mut self.0.0.0
+    }
+
+    /// Moves the file to `path`, replacing an existing file, and returns it still open, thus
+    /// the errors of closing it are returned by `LinearFile::close()`.
+    ///
+    /// # Errors
+    ///
+    /// When renaming fails, along with the temporary file to retry or `remove()` it.
+    pub fn persist(self, path: impl AsRef<Path>) -> Result<LinearFile, (Self, io::Error)> {
+        match std::fs::rename(self.path(), path) {
+            Ok(()) => Ok(LinearFile::new(self.0.into().0)),
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Closes and removes the file.
+    ///
+    /// # Errors
+    ///
+    /// When closing or removing fails. The file is removed even when closing fails.
+    pub fn remove(self) -> io::Result<()> {
+        let (file, temp) = self.0.into();
+        let closed = close(file);
+        closed.and(std::fs::remove_file(temp))
+    }
+}
+
+impl LinearTempDir {
+    /// Creates a temporary directory in `std::env::temp_dir()`.
+    ///
+    /// # Errors
+    ///
+    /// When the directory can not be created.
+    pub fn new() -> io::Result<Self> {
+        Self::new_in(std::env::temp_dir())
+    }
+
+    /// Creates a temporary directory in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// When the directory can not be created.
+    pub fn new_in(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let ((), path) = create_unique(dir.as_ref(), |path| std::fs::create_dir(path))?;
+        Ok(Self(__linear_from_parts(path)))
+    }
+
+    /// Returns the current path of the temporary directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0.0
+    }
+
+    /// Moves the directory to `path`.
+    ///
+    /// # Errors
+    ///
+    /// When renaming fails, along with the temporary directory to retry or `remove()` it.
+    pub fn persist(self, path: impl AsRef<Path>) -> Result<(), (Self, io::Error)> {
+        match std::fs::rename(self.path(), path) {
+            Ok(()) => {
+                drop(self.0.into());
+                Ok(())
+            }
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Removes the directory along with its content.
+    ///
+    /// # Errors
+    ///
+    /// When removing fails.
+    pub fn remove(self) -> io::Result<()> {
+        std::fs::remove_dir_all(self.0.into())
+    }
+}
+
+/// Creates a file system entry at a path not in use yet.
+fn create_unique<T>(
+    dir: &Path,
+    create: impl Fn(&Path) -> io::Result<T>,
+) -> io::Result<(T, PathBuf)> {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    loop {
+        let name = format!(
+            ".linear_ty-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = dir.join(name);
+        match create(&path) {
+            Ok(entry) => return Ok((entry, path)),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+impl Read for LinearTempFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.get_mut().read(buf)
+    }
+}
+
+impl Write for LinearTempFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl Seek for LinearTempFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.get_mut().seek(pos)
+    }
+}
+
+/// An owned file descriptor which must be explicitly closed. `Drop` of an `OwnedFd` ignores
+/// the result of `close(2)`, `close()` returns it. `into_owned_fd()` opts out.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::io::LinearFd;
+/// use std::os::fd::{AsRawFd, OwnedFd};
+///
+/// let fd = LinearFd::from(OwnedFd::from(std::fs::File::open(std::env::current_exe()?)?));
+/// assert!(fd.as_raw_fd() >= 0);
+/// fd.close()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(unix)]
+#[must_use]
+#[derive(Debug)]
+pub struct LinearFd(MustUse<std::os::fd::OwnedFd>);
+
+#[cfg(unix)]
+impl LinearFd {
+    /// Closes the file descriptor.
+    ///
+    /// # Errors
+    ///
+    /// When `close(2)` fails. The descriptor is released even then, it must not be closed
+    /// again, e.g. on `EINTR`.
+    pub fn close(self) -> io::Result<()> {
+        close(self.0.into())
+    }
+
+    /// Returns the owned file descriptor, which is closed on drop again.
+    pub fn into_owned_fd(self) -> std::os::fd::OwnedFd {
+        self.0.into()
+    }
+}
+
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for LinearFd {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self(__linear_from_parts(fd))
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::FromRawFd for LinearFd {
+    unsafe fn from_raw_fd(fd: std::os::fd::RawFd) -> Self {
+        // SAFETY: the caller guarantees that `fd` is open and owned.
+        Self::from(unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) })
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsRawFd for LinearFd {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.0.0.as_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsFd for LinearFd {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        self.0.0.as_fd()
+    }
+}
+
+/// A buffered writer which must be `finish()`ed. `Drop` of a `std::io::BufWriter` flushes the
+/// buffer and swallows the error, `finish()` flushes and returns it along with the writer.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::io::LinearBufWriter;
+/// use std::io::Write;
+///
+/// let mut writer = LinearBufWriter::new(Vec::new());
+/// writer.write_all(b"buffered")?;
+/// let (out, flushed) = writer.finish();
+/// flushed?;
+/// assert_eq!(out, b"buffered");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearBufWriter<W: Write>(MustUse<BufWriter<W>>);
+
+impl<W: Write> LinearBufWriter<W> {
+    /// Wraps a writer with a buffer of the default capacity.
+    pub fn new(writer: W) -> Self {
+        Self(__linear_from_parts(BufWriter::new(writer)))
+    }
+
+    /// Wraps a writer with a buffer of `capacity` bytes.
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        Self(__linear_from_parts(BufWriter::with_capacity(
+            capacity, writer,
+        )))
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[must_use]
+    pub fn get_ref(&self) -> &W {
+        self.0.0.get_ref()
+    }
+
+    /// Flushes the buffer and returns the writer along with the result of flushing. Data which
+    /// could not be flushed is discarded.
+    pub fn finish(self) -> (W, io::Result<()>) {
+        let mut writer = self.0.into();
+        let flushed = writer.flush();
+        let (writer, _unflushed) = writer.into_parts();
+        (writer, flushed)
+    }
+}
+
+impl<W: Write> Write for LinearBufWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.0.flush()
+    }
+}
+
+/// Closes a file descriptor and returns the error `Drop` would swallow.
+#[cfg(unix)]
+pub(crate) fn close(fd: impl std::os::fd::IntoRawFd) -> io::Result<()> {
+    // SAFETY: the descriptor is owned and closed exactly once, it is not used afterwards.
+    if unsafe { libc::close(fd.into_raw_fd()) } == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn close<T>(handle: T) -> io::Result<()> {
+    drop(handle);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearBufWriter, LinearFile, LinearTempDir, LinearTempFile};
+    use std::io::Write;
+
+    #[test]
+    fn into_std() {
+        let path = std::env::temp_dir().join("linear_ty_file_into_std");
+        let mut file = LinearFile::create(&path).unwrap();
+        file.write_all(b"std").unwrap();
+        drop(file.into_std());
+        assert_eq!(std::fs::read(&path).unwrap(), b"std");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearFile::open(std::env::current_exe().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn finish_failed() {
+        let mut buf = [0u8; 2];
+        let mut writer = LinearBufWriter::new(&mut buf[..]);
+        writer.write_all(b"overflow").unwrap();
+        let (rest, flushed) = writer.finish();
+        assert_eq!(flushed.unwrap_err().kind(), std::io::ErrorKind::WriteZero);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn persist_failed() {
+        let dir = LinearTempDir::new().unwrap();
+        let file = LinearTempFile::new_in(dir.path()).unwrap();
+        let temp = file.path().to_owned();
+        let missing = dir.path().join("missing").join("file");
+        let (file, _) = file.persist(missing).unwrap_err();
+        assert!(temp.exists());
+        let target = dir.path().join("file");
+        file.persist(&target).unwrap().close().unwrap();
+        assert!(!temp.exists() && target.exists());
+        dir.remove().unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fd_roundtrip() {
+        use super::LinearFd;
+        use std::os::fd::{AsRawFd, FromRawFd, IntoRawFd};
+
+        let file = std::fs::File::open(std::env::current_exe().unwrap()).unwrap();
+        let raw = file.into_raw_fd();
+        // SAFETY: the descriptor was just released by `into_raw_fd()`.
+        let fd = unsafe { LinearFd::from_raw_fd(raw) };
+        assert_eq!(fd.as_raw_fd(), raw);
+        let fd = LinearFd::from(fd.into_owned_fd());
+        fd.close().unwrap();
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{__linear_from_parts, __linearity, Linear, Linearity};
+
+/// Iterator over the elements of a `Linear<Vec<T>>` yielding each element as `Linear<T>`.
+///
+/// The iterator itself is linear, it must be driven to exhaustion. Each yielded item must be
+/// consumed on its own. Dropping the iterator while items are left is a linearity violation.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let permits = new_linear!(vec![1, 2, 3]);
+/// let mut sum = 0;
+/// for permit in permits.into_linear_iter() {
+///     sum += permit.into();
+/// }
+/// assert_eq!(sum, 6);
+/// ```
+#[must_use]
+pub struct LinearIter<T, U> {
+    items: std::vec::IntoIter<T>,
+    linearity: Option<Linearity<U>>,
+}
+
+impl<T, U> LinearIter<T, U> {
+    /// Iterates the elements of a vector as `Linear<T, U>` items.
+    pub(crate) fn from_vec(items: Vec<T>) -> Self {
+        Self {
+            items: items.into_iter(),
+            linearity: Some(__linearity::<U>()),
+        }
+    }
+
+    /// Finishes the iteration.
+    ///
+    /// # Errors
+    ///
+    /// When items are left the iterator is returned as `Err` to continue the iteration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let iter = new_linear!(vec![1]).into_linear_iter();
+    /// let mut iter = iter.finish().unwrap_err();
+    /// iter.next().unwrap().destroy();
+    /// assert!(iter.finish().is_ok());
+    /// ```
+    pub fn finish(self) -> Result<(), Self> {
+        if self.items.len() == 0 {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, U> Iterator for LinearIter<T, U> {
+    type Item = Linear<T, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next().map(__linear_from_parts)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<T, U> ExactSizeIterator for LinearIter<T, U> {}
+
+/// An exhausted iterator can be dropped, otherwise the linearity is dropped along with the
+/// iterator.
+impl<T, U> Drop for LinearIter<T, U> {
+    fn drop(&mut self) {
+        if self.items.len() == 0 {
+            core::mem::forget(self.linearity.take());
+        }
+    }
+}
+
+/// Additional `into_linear_iter()` method for `Linear<Vec<T>>`.
+impl<T, U> Linear<Vec<T>, U> {
+    /// Turns a `Linear<Vec<T>>` into a linear iterator yielding `Linear<T>` items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut iter = new_linear!(vec!["a", "b"]).into_linear_iter();
+    /// assert_eq!(iter.next().unwrap().into(), "a");
+    /// assert_eq!(iter.next().unwrap().into(), "b");
+    /// assert!(iter.finish().is_ok());
+    /// ```
+    pub fn into_linear_iter(self) -> LinearIter<T, Self> {
+        LinearIter::from_vec(self.into())
+    }
+}
+
+/// Additional element-wise consuming and partitioning methods for `Linear<Vec<T>>`.
+impl<T, U> Linear<Vec<T>, U> {
+    /// Consumes each element with a closure and collects the results into a new linear vector.
+    /// The collection stays linear without unwrapping it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let lengths = new_linear!(vec!["a", "bb"]).drain_consume(str::len);
+    /// assert_eq!(lengths.into(), vec![1, 2]);
+    /// ```
+    pub fn drain_consume<F: FnMut(T) -> R, R>(self, f: F) -> Linear<Vec<R>, Self> {
+        __linear_from_parts(self.into().into_iter().map(f).collect())
+    }
+
+    /// Consumes each element with a closure, this terminates the linear vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut sent = Vec::new();
+    /// new_linear!(vec![1, 2]).for_each_consume(|x| sent.push(x));
+    /// assert_eq!(sent, [1, 2]);
+    /// ```
+    pub fn for_each_consume<F: FnMut(T)>(self, f: F) {
+        self.into().into_iter().for_each(f);
+    }
+
+    /// Splits the linear vector into the elements matching the predicate and the others. Both
+    /// halves stay linear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (now, deferred) = new_linear!(vec![1, 2, 3, 4]).partition(|x| x % 2 == 0);
+    /// assert_eq!(now.into(), vec![2, 4]);
+    /// assert_eq!(deferred.into(), vec![1, 3]);
+    /// ```
+    pub fn partition<F: FnMut(&T) -> bool>(
+        self,
+        f: F,
+    ) -> (Linear<Vec<T>, Self>, Linear<Vec<T>, Self>) {
+        let (matching, others) = self.into().into_iter().partition(f);
+        (__linear_from_parts(matching), __linear_from_parts(others))
+    }
+}
+
+/// Additional batching methods for `Linear<Vec<T>>`. A `LinearIter` is batched by collecting
+/// it into a `Linear<Vec<T>>` first.
+impl<T, U> Linear<Vec<T>, U> {
+    /// Consumes the elements in batches of `n`, the last batch may be shorter. Each batch is
+    /// delivered as a linear vector and the results are collected into a new linear vector.
+    ///
+    /// # Panics
+    ///
+    /// When `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let rows = new_linear!(vec![1, 2, 3, 4, 5]).into_linear_iter();
+    /// let rows: Linear<Vec<_>, _> = rows.collect();
+    /// let inserted = rows.chunks_consume(2, |batch| batch.into().len());
+    /// assert_eq!(inserted.into(), vec![2, 2, 1]);
+    /// ```
+    pub fn chunks_consume<F: FnMut(Linear<Vec<T>, Self>) -> R, R>(
+        self,
+        n: usize,
+        mut f: F,
+    ) -> Linear<Vec<R>, Self> {
+        assert!(n > 0, "chunks are at least one element long");
+        let mut items = self.into().into_iter().peekable();
+        let mut results = Vec::new();
+        while items.peek().is_some() {
+            results.push(f(__linear_from_parts(items.by_ref().take(n).collect())));
+        }
+        __linear_from_parts(results)
+    }
+
+    /// Consumes consecutive elements with equal keys in batches. Each batch is delivered along
+    /// with its key as a linear vector and the results are collected into a new linear vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let events = new_linear!(vec![("a", 1), ("a", 2), ("b", 3)]);
+    /// let sums = events.group_by_consume(
+    ///     |(table, _)| *table,
+    ///     |table, batch| (table, batch.into().iter().map(|(_, x)| x).sum::<i32>()),
+    /// );
+    /// assert_eq!(sums.into(), vec![("a", 3), ("b", 3)]);
+    /// ```
+    pub fn group_by_consume<K, KF, F, R>(self, mut key: KF, mut f: F) -> Linear<Vec<R>, Self>
+    where
+        K: PartialEq,
+        KF: FnMut(&T) -> K,
+        F: FnMut(K, Linear<Vec<T>, Self>) -> R,
+    {
+        let mut results = Vec::new();
+        let mut group: Option<(K, Vec<T>)> = None;
+        for item in self.into() {
+            let item_key = key(&item);
+            match &mut group {
+                Some((group_key, batch)) if *group_key == item_key => batch.push(item),
+                _ => {
+                    if let Some((group_key, batch)) = group.replace((item_key, vec![item])) {
+                        results.push(f(group_key, __linear_from_parts(batch)));
+                    }
+                }
+            }
+        }
+        if let Some((group_key, batch)) = group {
+            results.push(f(group_key, __linear_from_parts(batch)));
+        }
+        __linear_from_parts(results)
+    }
+}
+
+/// Additional `collect_ok()` method for `Linear<Vec<Result<T,E>>>`.
+impl<T, E, U> Linear<Vec<Result<T, E>>, U> {
+    /// Transforms a `Linear<Vec<Result<T,E>>>` into a `Linear<Result<Vec<T>,E>>`. Stops at the
+    /// first `Err` value. The `Ok` values collected so far and all remaining values are dropped
+    /// as plain values, the linearity is carried on by the returned `Linear<Result<_,E>>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let results = new_linear!(vec!["1".parse::<i32>(), "2".parse::<i32>()]);
+    /// assert_eq!(results.collect_ok().unwrap_ok().into(), vec![1, 2]);
+    ///
+    /// let results = new_linear!(vec!["1".parse::<i32>(), "x".parse::<i32>()]);
+    /// results.collect_ok().unwrap_err().destroy();
+    /// ```
+    pub fn collect_ok(self) -> Linear<Result<Vec<T>, E>, Self> {
+        __linear_from_parts(self.into().into_iter().collect())
+    }
+}
+
+/// Collects linear items into a single linear collection. The linearity of each item is
+/// consumed, the resulting `Linear<Vec<T>>` is tagged with the type of the items.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let items = new_linear!(vec![1, 2, 3]).into_linear_iter().map(|x| x.map(|x| x * 2));
+/// let doubled: Linear<Vec<_>, _> = items.collect();
+/// assert_eq!(doubled.into(), vec![2, 4, 6]);
+/// ```
+impl<T, U> FromIterator<Linear<T, U>> for Linear<Vec<T>, Linear<T, U>> {
+    fn from_iter<I: IntoIterator<Item = Linear<T, U>>>(iter: I) -> Self {
+        __linear_from_parts(iter.into_iter().map(Linear::into).collect())
+    }
+}
+
+/// Turns a vector of linear values into a single `Linear<Vec<T>>`. This is the fan in
+/// counterpart of `into_linear_iter()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut iter = new_linear!(vec![1, 2]).into_linear_iter();
+/// let items = vec![iter.next().unwrap(), iter.next().unwrap()];
+/// assert!(iter.finish().is_ok());
+/// let collected = sequence(items);
+/// assert_eq!(collected.into(), vec![1, 2]);
+/// ```
+pub fn sequence<T, U>(items: Vec<Linear<T, U>>) -> Linear<Vec<T>, Linear<T, U>> {
+    items.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn exhausted() {
+        let iter = crate::new_linear!(vec![1, 2]).into_linear_iter();
+        assert_eq!(iter.len(), 2);
+        let sum: i32 = iter.map(crate::Linear::into).sum();
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped_mid_way() {
+        let mut iter = crate::new_linear!(vec![1, 2]).into_linear_iter();
+        iter.next().unwrap().destroy();
+    }
+
+    #[test]
+    fn sequence_empty() {
+        let empty: Vec<crate::MustUse<u8>> = Vec::new();
+        assert!(super::sequence(empty).into().is_empty());
+    }
+
+    #[test]
+    fn drain_consume() {
+        let mut sum = 0;
+        crate::new_linear!(vec![1, 2, 3])
+            .drain_consume(|x| x * 2)
+            .for_each_consume(|x| sum += x);
+        assert_eq!(sum, 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn partition_half_dropped() {
+        let (now, _) = crate::new_linear!(vec![1, 2]).partition(|x| *x > 1);
+        now.destroy();
+    }
+
+    #[test]
+    fn batches() {
+        let chunks = crate::new_linear!(vec![1, 2, 3]).chunks_consume(3, crate::Linear::into);
+        assert_eq!(chunks.into(), vec![vec![1, 2, 3]]);
+        let empty = crate::new_linear!(Vec::<u8>::new());
+        let groups = empty.group_by_consume(|x| *x, |_, batch| batch.destroy());
+        assert!(groups.into().is_empty());
+    }
+}
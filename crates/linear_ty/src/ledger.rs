@@ -0,0 +1,139 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// A linear debit of an amount, it must be settled against credits of the same total amount
+/// in `Ledger::settle()`.
+#[must_use]
+pub struct Debit(MustUse<u64>);
+
+/// A linear credit of an amount, it must be settled against a debit in `Ledger::settle()`.
+#[must_use]
+pub struct Credit(MustUse<u64>);
+
+impl Debit {
+    /// Creates a debit of `amount`.
+    pub const fn new(amount: u64) -> Self {
+        Self(__linear_from_parts(amount))
+    }
+
+    /// Returns the amount of the debit.
+    #[must_use]
+    pub fn amount(&self) -> u64 {
+        *self.0.0
+    }
+}
+
+impl Credit {
+    /// Creates a credit of `amount`.
+    pub const fn new(amount: u64) -> Self {
+        Self(__linear_from_parts(amount))
+    }
+
+    /// Returns the amount of the credit.
+    #[must_use]
+    pub fn amount(&self) -> u64 {
+        *self.0.0
+    }
+}
+
+/// Settles debits against credits, enforcing double-entry: every debit is matched by credits
+/// summing to the same amount.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut ledger = Ledger::new();
+/// let payment = Debit::new(100);
+/// ledger.settle(payment, [Credit::new(70), Credit::new(30)]);
+/// assert_eq!(ledger.settled(), 100);
+/// ```
+#[derive(Debug, Default)]
+pub struct Ledger {
+    settled: u128,
+}
+
+impl Ledger {
+    /// Creates an empty ledger.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { settled: 0 }
+    }
+
+    /// Consumes a debit together with the credits matching it.
+    ///
+    /// # Panics or Aborts
+    ///
+    /// Like a dropped linear value, when the credits do not sum to the amount of the debit.
+    pub fn settle(&mut self, debit: Debit, credits: impl IntoIterator<Item = Credit>) {
+        // Sums are taken in `u128`, they can not overflow for any realistic number of credits.
+        let debited = u128::from(debit.0.into());
+        let credited = credits
+            .into_iter()
+            .map(|credit| u128::from(credit.0.into()))
+            .sum();
+        if debited != credited {
+            unbalanced(debited, credited);
+        }
+        self.settled += debited;
+    }
+
+    /// Returns the total amount settled.
+    #[must_use]
+    pub const fn settled(&self) -> u128 {
+        self.settled
+    }
+}
+
+#[cfg(test)]
+fn unbalanced(debited: u128, credited: u128) {
+    panic!("unbalanced settlement: debited {debited}, credited {credited}");
+}
+
+#[cfg(all(not(test), feature = "std"))]
+fn unbalanced(debited: u128, credited: u128) {
+    eprintln!("unbalanced settlement: debited {debited}, credited {credited}");
+    std::process::abort();
+}
+
+#[cfg(not(any(test, feature = "std")))]
+fn unbalanced(debited: u128, credited: u128) {
+    panic!("unbalanced settlement: debited {debited}, credited {credited}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Credit, Debit, Ledger};
+
+    #[test]
+    fn settled() {
+        let mut ledger = Ledger::new();
+        let debit = Debit::new(5);
+        assert_eq!(debit.amount(), 5);
+        let credits: Vec<_> = (0..5).map(|_| Credit::new(1)).collect();
+        assert_eq!(credits.iter().map(Credit::amount).sum::<u64>(), 5);
+        ledger.settle(debit, credits);
+        ledger.settle(Debit::new(0), []);
+        assert_eq!(ledger.settled(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "unbalanced settlement: debited 10, credited 9")]
+    fn unbalanced() {
+        Ledger::new().settle(Debit::new(10), [Credit::new(9)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "debited 18446744073709551615, credited 18446744073709551616")]
+    fn overflowing_credits() {
+        Ledger::new().settle(
+            Debit::new(u64::MAX),
+            [Credit::new(u64::MAX), Credit::new(1)],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn credit_dropped() {
+        let _ = Credit::new(1);
+    }
+}
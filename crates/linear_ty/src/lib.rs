@@ -1,10 +1,278 @@
 #![doc = include_str!("../../../README.md")]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(
+        try_trait_v2,
+        try_trait_v2_residual,
+        must_not_suspend,
+        allow_internal_unstable
+    )
+)]
+#![cfg_attr(feature = "nightly", allow(internal_features))]
 
 /// Helpers for exhaustive field access via [`Parts`] and the [`parts!`] macro.
 #[macro_use]
 pub mod parts;
 pub use parts::Parts;
 
+/// Linear iteration over the elements of linear collections, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod iter;
+#[cfg(feature = "std")]
+pub use iter::{LinearIter, sequence};
+
+/// Heap storage for large linear values, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod boxed;
+
+/// Pinned storage and projections for linear values, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod pin;
+
+/// Scoped consumption with compile time proof.
+pub mod scope;
+pub use scope::{Consumed, Scoped};
+
+/// Deferred consumers registered on linear values.
+pub mod finally;
+pub use finally::Finalizer;
+
+/// Session types, linear channel endpoints following dual protocols which must be driven to
+/// their end, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod session;
+
+/// Obligations carrying a description which is reported when they are dropped.
+pub mod obligation;
+pub use obligation::{DropBomb, Fulfillment, Obligation};
+
+/// Tokens which must be spent an exact number of times.
+pub mod credits;
+pub use credits::Credits;
+
+/// Read-only shares of a linear value which must all be joined back, available with the `std`
+/// feature.
+#[cfg(feature = "std")]
+pub mod share;
+#[cfg(feature = "std")]
+pub use share::Share;
+
+/// Relevant and affine values, used at least once and at most once.
+pub mod substructural;
+pub use substructural::{Affine, Relevant};
+
+/// Slots filled exactly once and taken exactly once through linear tokens, available with the `std`
+/// feature.
+#[cfg(feature = "std")]
+pub mod cell;
+#[cfg(feature = "std")]
+pub use cell::{Getter, LinearCell, Setter};
+
+/// Two-phase commits, prepared changes which must be committed or aborted.
+pub mod prepared;
+pub use prepared::{Prepared, TwoPhase};
+
+/// Transactions which must be committed or rolled back.
+pub mod transaction;
+pub use transaction::LinearTransaction;
+
+/// Pools lending their items as linear leases which must be given back, available with the `std`
+/// feature.
+#[cfg(feature = "std")]
+pub mod pool;
+#[cfg(feature = "std")]
+pub use pool::{Lease, Pool};
+
+/// Double-entry debits and credits which must be settled against each other.
+pub mod ledger;
+pub use ledger::{Credit, Debit, Ledger};
+
+/// Message acknowledgement tokens minted by queue adapters.
+pub mod ack;
+pub use ack::{AckToken, Acknowledger};
+
+/// Semaphores handing out linear permits which must be explicitly released, available with the
+/// `std` feature.
+#[cfg(feature = "std")]
+pub mod semaphore;
+#[cfg(feature = "async")]
+pub use semaphore::Acquire;
+#[cfg(feature = "std")]
+pub use semaphore::{LinearSemaphore, Permit};
+
+/// Zero sized proofs emitted by consuming a linear value through a checked property.
+pub mod proof;
+pub use proof::{Property, Proven};
+
+/// Closures which must be called exactly once.
+pub mod fn_once;
+pub use fn_once::{CallOnce, LinearFnOnce};
+
+/// Regions checking that all tokens minted in them are consumed.
+pub mod region;
+pub use region::{Region, RegionToken};
+
+/// Vectors and stacks of linear elements which must be drained, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod vec;
+#[cfg(feature = "std")]
+pub use vec::{LinearStack, LinearVec};
+
+/// Maps of linear values which must all be removed, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod map;
+#[cfg(feature = "std")]
+pub use map::LinearMap;
+
+/// Slabs storing values under linear keys, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod slab;
+#[cfg(feature = "std")]
+pub use slab::{Slab, SlabKey};
+
+/// Threads and scoped threads with linear join handles, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod thread;
+#[cfg(feature = "std")]
+pub use thread::LinearJoinHandle;
+
+/// Oneshot and mpsc channels with linear halves and linear messages, available with the `std`
+/// feature.
+#[cfg(feature = "std")]
+pub mod channel;
+
+/// Mutex and reader-writer lock with linear guards which must be explicitly unlocked, available
+/// with the `std` feature.
+#[cfg(feature = "std")]
+pub mod lock;
+#[cfg(feature = "std")]
+pub use lock::{LinearMutex, LinearMutexGuard, LinearReadGuard, LinearRwLock, LinearWriteGuard};
+
+/// Cells initialized exactly once and taken exactly once through linear tokens, available with the
+/// `std` feature.
+#[cfg(feature = "std")]
+pub mod once_cell;
+#[cfg(feature = "std")]
+pub use once_cell::{Initializer, LinearOnceCell, Taker};
+
+/// Cancellation tokens which must be resolved and whose outcome must be acknowledged, available
+/// with the `std` feature.
+#[cfg(feature = "std")]
+pub mod cancel;
+#[cfg(feature = "std")]
+pub use cancel::{CancelObserver, CancelOutcome, CancelToken};
+
+/// Raw handles of C libraries which must be released by their destructor.
+pub mod ffi;
+
+/// Encoders which must be finished, through the `Finish` trait, available with the `std` feature.
+#[cfg(feature = "std")]
+pub mod finish;
+#[cfg(feature = "std")]
+pub use finish::{Finish, MustFinish};
+
+/// Linear guards turning `Drop` based resources into explicitly finalized ones.
+pub mod guard;
+pub use guard::LinearGuard;
+
+/// Linear state machines generated by the [`typestate!`] macro.
+#[macro_use]
+pub mod typestate;
+pub use typestate::Transition;
+
+/// Linear typestate builders generated by the [`builder!`] macro.
+#[macro_use]
+pub mod builder;
+
+/// Macros for consuming and destructuring linear values.
+#[macro_use]
+mod macros;
+#[doc(hidden)]
+pub use macros::{__Chain, __ChainOk, __ChainPlain, __IntoInners, __LinearTry, __consume_all};
+
+/// `?` operator support for `Linear<Result<T,E>>`.
+#[cfg(feature = "nightly")]
+mod try_trait;
+
+/// Helpers for compile fail tests of linear types in downstream crates, available with the
+/// `test-util` feature.
+#[cfg(feature = "test-util")]
+#[macro_use]
+pub mod test_util;
+
+/// Fixed capacity linear collections backed by `heapless`, available with the `heapless`
+/// feature.
+#[cfg(feature = "heapless")]
+pub mod heapless;
+
+/// Linear futures, available with the `async` feature.
+#[cfg(feature = "async")]
+pub mod future;
+#[cfg(feature = "async")]
+pub use future::LinearFuture;
+
+/// Linear streams and consuming stream adapters, available with the `futures` feature.
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "futures")]
+pub use stream::{LinearStream, LinearStreamExt};
+
+/// Parallel consumption of `Linear<Vec<T>>` on the rayon thread pool, available with the
+/// `rayon` feature.
+#[cfg(feature = "rayon")]
+mod rayon;
+
+/// Background watchdog reporting linear values not consumed before a deadline, available
+/// with the `watchdog` feature.
+#[cfg(feature = "watchdog")]
+pub mod watchdog;
+
+/// IO resources which must be explicitly closed, returning the errors `Drop` swallows,
+/// available with the `io` feature.
+#[cfg(feature = "io")]
+pub mod io;
+
+/// Sockets which must be explicitly shut down or closed, with linear read and write halves,
+/// available with the `io` feature.
+#[cfg(feature = "io")]
+pub mod net;
+
+/// Child processes which must be reaped, available with the `io` feature.
+#[cfg(feature = "io")]
+pub mod process;
+
+/// Writable memory maps which must be flushed and unmapped explicitly, available with the
+/// `mmap` feature.
+#[cfg(feature = "mmap")]
+pub mod mmap;
+
+/// HTTP responses whose bodies must be consumed, available with the `http` feature.
+#[cfg(feature = "http")]
+pub mod http;
+
+/// Linear tokio task handles, available with the `tokio` feature.
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+/// Tower layer wrapping service responses in a linear type, available with the `tower`
+/// feature.
+#[cfg(feature = "tower")]
+pub mod tower;
+
+/// Linear rusqlite transactions, available with the `rusqlite` feature.
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+
+/// Linear sqlx transactions, available with the `sqlx` feature.
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
+/// `#[derive(Linear)]` generating a linear companion newtype and `#[must_consume]` wrapping
+/// return values in `MustUse<T>`, available with the `derive` feature.
+#[cfg(feature = "derive")]
+pub use linear_ty_derive::{Linear, must_consume};
+
 use core::mem::ManuallyDrop;
 
 /// Linearity holder. Carries the unique type marker and ensures a linear value is not dropped.
@@ -65,22 +333,584 @@ macro_rules! deny_non_exhaustive {
     };
 }
 
+/// Generates `split()` for linear tuples from arity two up to the length of the given list.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __linear_split {
+    ($name:ident, $u:ident; [$($ty:ident $var:ident)+]) => {
+        /// Additional `split()` method for linear tuples.
+        impl<$($ty,)+ $u> $name<($($ty,)+), $u> {
+            /// Splits a linear tuple into a tuple of linear values. Each of them must be
+            /// consumed on its own.
+            pub fn split(self) -> ($($name<$ty, Self>,)+) {
+                let ($($var,)+) = self.into();
+                ($(Self::transition($var),)+)
+            }
+        }
+    };
+    ($name:ident, $u:ident; [$($ty:ident $var:ident)+] $next:ident $next_var:ident $($rest:ident)*) => {
+        $crate::__linear_split!($name, $u; [$($ty $var)+]);
+        $crate::__linear_split!($name, $u; [$($ty $var)+ $next $next_var] $($rest)*);
+    };
+}
+
+/// Expands the given items only when this crate is compiled with the `semipure` feature. This
+/// keeps the feature of this crate and not the one of the expanding crate in charge.
+#[cfg(any(doc, feature = "semipure"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __semipure {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(any(doc, feature = "semipure")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __semipure {
+    ($($item:tt)*) => {};
+}
+
+/// Expands the given items only when this crate is compiled with the `semipure_mut` feature.
+#[cfg(any(doc, feature = "semipure_mut"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __semipure_mut {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(any(doc, feature = "semipure_mut")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __semipure_mut {
+    ($($item:tt)*) => {};
+}
+
+/// Annotates the given item with `#[must_not_suspend]` only when this crate is compiled with
+/// the `nightly` feature. Holding such a value across an `.await` triggers the
+/// `must_not_suspend` lint in the expanding crate.
+#[cfg(feature = "nightly")]
+#[doc(hidden)]
+#[macro_export]
+#[allow_internal_unstable(must_not_suspend)]
+macro_rules! __must_not_suspend {
+    ($item:item) => {
+        #[must_not_suspend = "linear values must be consumed, not held across an `.await`"]
+        $item
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __must_not_suspend {
+    ($item:item) => {
+        $item
+    };
+}
+
+/// Generates a linear newtype with a fixed unique type, the optional `where` clause is collected
+/// up to the terminating `;`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __linear_newtype {
+    (@start $header:tt ; $($delegates:tt)*) => {
+        $crate::__linear_newtype!(@emit $header [] [$($delegates)*]);
+    };
+    (@start $header:tt where $($rest:tt)+) => {
+        $crate::__linear_newtype!(@where $header [] $($rest)+);
+    };
+    (@where $header:tt [$($w:tt)*] ; $($delegates:tt)*) => {
+        $crate::__linear_newtype!(@emit $header [$($w)*] [$($delegates)*]);
+    };
+    (@where $header:tt [$($w:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::__linear_newtype!(@where $header [$($w)* $next] $($rest)+);
+    };
+    (@new ($inner:ty)) => {
+        /// Constructs a new value with the fixed `U` type.
+        pub const fn new(inner: $inner) -> Self {
+            Self(
+                ::core::mem::ManuallyDrop::new(inner),
+                $crate::__linearity::<$crate::UniqueType<fn()>>(),
+            )
+        }
+    };
+    (@new {$($field:ident: $ty:ty),*}) => {
+        /// Constructs a new value from its fields.
+        pub const fn new($($field: $ty),*) -> Self {
+            Self(
+                ::core::mem::ManuallyDrop::new(($($field,)*)),
+                $crate::__linearity::<$crate::UniqueType<fn()>>(),
+            )
+        }
+    };
+    (
+        @emit {
+            $(#[$($meta:tt)*])*
+            $vis:vis struct $name:ident [$($g:ident)*] ($inner:ty) $ctor:tt
+        } [$($w:tt)*] [$($delegates:tt)*]
+    ) => {
+        $crate::__must_not_suspend! {
+            $(#[$($meta)*])*
+            #[must_use]
+            $vis struct $name<$($g),*>(
+                ::core::mem::ManuallyDrop<$inner>,
+                $crate::Linearity<$crate::UniqueType<fn()>>,
+            )
+            where
+                $($w)*;
+        }
+
+        // Trait bounds on the inner type are higher ranked, they would be rejected as trivial
+        // bounds when the inner type is concrete otherwise.
+
+        /// Compares only inner value.
+        impl<$($g),*> ::core::cmp::PartialEq for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::cmp::PartialEq,
+            $($w)*
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$($g),*> ::core::cmp::Eq for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::cmp::Eq,
+            $($w)*
+        {
+        }
+
+        /// Compares only inner value.
+        #[allow(clippy::non_canonical_partial_ord_impl)]
+        impl<$($g),*> ::core::cmp::PartialOrd for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::cmp::PartialOrd,
+            $($w)*
+        {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        /// Compares only inner value.
+        impl<$($g),*> ::core::cmp::Ord for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::cmp::Ord,
+            $($w)*
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        /// Hashes only inner value.
+        impl<$($g),*> ::core::hash::Hash for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::hash::Hash,
+            $($w)*
+        {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        /// Custom debug outputing value and lifetime type
+        impl<$($g),*> ::core::fmt::Debug for $name<$($g),*>
+        where
+            for<'__l> $inner: ::core::fmt::Debug,
+            $($w)*
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.0)
+                    .field(&::core::any::type_name::<$crate::UniqueType<fn()>>())
+                    .finish()
+            }
+        }
+
+        // The semipure methods may be unused by private newtypes.
+        #[allow(dead_code)]
+        impl<$($g),*> $name<$($g),*>
+        where
+            $($w)*
+        {
+            $crate::__linear_newtype!(@new $ctor);
+
+            $crate::__linear_delegate!($($delegates)*);
+
+            $crate::__semipure! {
+                /// Returns a reference to the inner value.
+                ///
+                /// # Safety
+                ///
+                /// The inner value must not be cloned or mutated through interior mutability.
+                pub unsafe fn get_ref(&self) -> &$inner {
+                    &self.0
+                }
+            }
+
+            $crate::__semipure! {
+                /// Calls a closure with a reference to the inner value and returns the linear type
+                /// unchanged.
+                ///
+                /// # Safety
+                ///
+                /// The closure must not clone the inner value or mutate it through interior
+                /// mutability.
+                pub unsafe fn inspect<F: FnOnce(&$inner)>(self, f: F) -> Self {
+                    f(&self.0);
+                    self
+                }
+            }
+
+            $crate::__semipure_mut! {
+                /// Returns a mutable reference to the inner value.
+                ///
+                /// # Safety
+                ///
+                /// The inner value must not be replaced by a value made up from thin air.
+                pub unsafe fn get_mut(&mut self) -> &mut $inner {
+                    &mut self.0
+                }
+            }
+
+            $crate::__semipure_mut! {
+                /// Replaces the inner value with `value` and returns the old inner value.
+                ///
+                /// # Safety
+                ///
+                /// The new value must be a legitimate substitute for the old one.
+                pub unsafe fn replace(&mut self, value: $inner) -> $inner {
+                    ::core::mem::replace(&mut *self.0, value)
+                }
+            }
+
+            $crate::__semipure_mut! {
+                /// Swaps the inner values of two linear types.
+                ///
+                /// # Safety
+                ///
+                /// Each value must be a legitimate substitute for the other one.
+                pub unsafe fn swap(&mut self, other: &mut Self) {
+                    ::core::mem::swap(&mut *self.0, &mut *other.0);
+                }
+            }
+
+            /// Destructures the linear type and returns the inner type.  This must eventually be called on
+            /// any linear type, failing to do so will panic when the linear type is dropped.
+            pub fn into(self) -> $inner {
+                let $name(t, linearity) = self;
+                ::core::mem::forget(linearity);
+                ::core::mem::ManuallyDrop::into_inner(t)
+            }
+
+            /// Consumes and destroys the wrapped value. This is like `into()` and them dropping
+            /// the returned value.
+            #[inline]
+            pub fn destroy(mut self) {
+                unsafe {
+                    ::core::mem::ManuallyDrop::drop(&mut self.0);
+                }
+                let $name(_, linearity) = self;
+                ::core::mem::forget(linearity);
+            }
+
+            /// Transforms one linear type to another linear type. The inner value is passed to the
+            /// closure and the return value is wrapped in a `Linear`.
+            pub fn map<F: FnOnce($inner) -> R, R>(self, f: F) -> $crate::Linear<R, Self> {
+                $crate::__linear_from_parts::<R, Self>(f(self.into()))
+            }
+
+            /// Transforms one linear type to another linear type with a closure that itself
+            /// returns a `Linear`. The returned linear value is consumed and its inner value is
+            /// rewrapped.
+            pub fn and_then<F: FnOnce($inner) -> $crate::Linear<R, V>, R, V>(
+                self,
+                f: F,
+            ) -> $crate::Linear<R, Self> {
+                $crate::__linear_from_parts::<R, Self>(f(self.into()).into())
+            }
+
+            /// Consumes the linear type by applying a closure to the inner value. The result of
+            /// the closure is returned as is.
+            pub fn consume<F: FnOnce($inner) -> R, R>(self, f: F) -> R {
+                f(self.into())
+            }
+        }
+    };
+}
+
+/// Generates the hidden state enum, constructors and `match_consume()` of a linear enum.
+/// Field bindings are created one per expansion step, macro hygiene keeps them distinct.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __linear_enum {
+    (
+        @emit
+        $(#[$($meta:tt)*])*
+        $vis:vis enum $name:ident {
+            $($(#[$($vmeta:tt)*])* $variant:ident ($($field:ty),*)),*
+        }
+    ) => {
+        $crate::__must_not_suspend! {
+            $(#[$($meta)*])*
+            #[must_use]
+            $vis struct $name(
+                ::core::mem::ManuallyDrop<<$name as $crate::__LinearEnum>::State>,
+                $crate::Linearity<$crate::UniqueType<fn()>>,
+            );
+        }
+
+        const _: () = {
+            pub enum __LinearEnumState {
+                $($variant($($field),*)),*
+            }
+
+            impl $crate::__LinearEnum for $name {
+                type State = __LinearEnumState;
+            }
+
+            impl $name {
+                $(
+                    $crate::__linear_enum!(@ctor [$(#[$($vmeta)*])*] $vis $variant [] [$($field),*]);
+                )*
+
+                /// Consumes the linear enum by calling the closure matching its variant with
+                /// the fields of the variant. Closures are given in the order of the variants.
+                #[allow(non_snake_case)]
+                $vis fn match_consume<R>(
+                    self,
+                    $($variant: impl FnOnce($($field),*) -> R),*
+                ) -> R {
+                    let $name(state, linearity) = self;
+                    ::core::mem::forget(linearity);
+                    let state = ::core::mem::ManuallyDrop::into_inner(state);
+                    $crate::__linear_enum!(@match state [] $($variant [] [$($field),*])*)
+                }
+
+                /// Consumes and destroys the linear enum.
+                $vis fn destroy(mut self) {
+                    unsafe {
+                        ::core::mem::ManuallyDrop::drop(&mut self.0);
+                    }
+                    let $name(_, linearity) = self;
+                    ::core::mem::forget(linearity);
+                }
+            }
+        };
+    };
+    (@ctor [$($attr:tt)*] $vis:vis $variant:ident [$($b:ident $t:ty),*] []) => {
+        $($attr)*
+        #[allow(non_snake_case)]
+        $vis const fn $variant($($b: $t),*) -> Self {
+            Self(
+                ::core::mem::ManuallyDrop::new(__LinearEnumState::$variant($($b),*)),
+                $crate::__linearity::<$crate::UniqueType<fn()>>(),
+            )
+        }
+    };
+    (@ctor [$($attr:tt)*] $vis:vis $variant:ident [$($b:ident $t:ty),*] [$next:ty $(, $rest:ty)*]) => {
+        $crate::__linear_enum!(
+            @ctor [$($attr)*] $vis $variant [$($b $t,)* field $next] [$($rest),*]
+        );
+    };
+    (@match $state:ident [$($arms:tt)*]) => {
+        match $state {
+            $($arms)*
+        }
+    };
+    (@match $state:ident [$($arms:tt)*] $variant:ident [$($b:ident)*] [] $($more:tt)*) => {
+        $crate::__linear_enum!(
+            @match $state [$($arms)* __LinearEnumState::$variant($($b),*) => $variant($($b),*),] $($more)*
+        )
+    };
+    (@match $state:ident [$($arms:tt)*] $variant:ident [$($b:ident)*] [$next:ty $(, $rest:ty)*] $($more:tt)*) => {
+        $crate::__linear_enum!(@match $state [$($arms)*] $variant [$($b)* field] [$($rest),*] $($more)*)
+    };
+}
+
+/// Generates the methods listed in a `#[delegate] impl { .. }` block of `linear!`. Methods
+/// taking `self` consume the linear value, methods taking `&self` require `semipure`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __linear_delegate {
+    () => {};
+    (#[delegate] impl { $($methods:tt)* }) => {
+        $crate::__linear_delegate!(@method $($methods)*);
+    };
+    (@method) => {};
+    (
+        @method
+        $(#[$($meta:tt)*])*
+        fn $method:ident(self $(, $arg:ident: $ty:ty)* $(,)?) $(-> $ret:ty)?;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("Consumes the linear value and delegates to `", stringify!($method), "()` of the inner value.")]
+        $(#[$($meta)*])*
+        pub fn $method(self $(, $arg: $ty)*) $(-> $ret)? {
+            self.into().$method($($arg),*)
+        }
+
+        $crate::__linear_delegate!(@method $($rest)*);
+    };
+    (
+        @method
+        $(#[$($meta:tt)*])*
+        fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) $(-> $ret:ty)?;
+        $($rest:tt)*
+    ) => {
+        $crate::__semipure! {
+            #[doc = concat!("Delegates to `", stringify!($method), "()` of the inner value.")]
+            $(#[$($meta)*])*
+            ///
+            /// # Safety
+            ///
+            /// This is as impure as `get_ref()`. The method must not clone the inner value or
+            /// mutate it through interior mutability.
+            pub unsafe fn $method(&self $(, $arg: $ty)*) $(-> $ret)? {
+                unsafe { self.get_ref() }.$method($($arg),*)
+            }
+        }
+
+        $crate::__linear_delegate!(@method $($rest)*);
+    };
+}
+
+/// Gives access to the hidden state enum of linear enums generated by `linear!`.
+#[doc(hidden)]
+pub trait __LinearEnum {
+    /// The hidden state enum.
+    type State;
+}
+
 /// Generates linear newtype from newtype name and inner value type.
 /// `Linear<T, U>` is just generated generic variant with some added extra helpers for uniquness
+///
+/// Other newtypes may have any number of type parameters and a `where` clause, the unique type
+/// is then fixed. Only `struct Name<T, U>(T);`, two type parameters wrapping the first one,
+/// generates the `Linear<T, U>` like variant where the second parameter is the unique type. Two
+/// type parameters wrapping another plain type name are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// linear! {
+///     /// Linear buffer of displayable items.
+///     pub struct Buffer<T>(Vec<T>) where T: core::fmt::Display;
+/// }
+///
+/// let buffer = Buffer::new(vec![1, 2]);
+/// let text = buffer.map(|items| items.iter().map(ToString::to_string).collect::<String>());
+/// assert_eq!(text.into(), "12");
+/// ```
+///
+/// ```compile_fail
+/// use linear_ty::*;
+///
+/// linear! {
+///     pub struct Named<A, B>(String);
+/// }
+/// ```
+///
+/// Structs with named fields store their fields as a tuple, `new()` takes the fields in order
+/// and `into()` returns the tuple. Methods of the inner value can be delegated with a
+/// `#[delegate] impl` block following the struct, methods taking `&self` are only available
+/// with the `semipure` feature.
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// linear! {
+///     /// Access token.
+///     pub struct Token {
+///         id: u64,
+///         secret: String,
+///     }
+/// }
+///
+/// linear! {
+///     /// Linear name.
+///     pub struct Name(String);
+///
+///     #[delegate]
+///     impl {
+///         fn into_bytes(self) -> Vec<u8>;
+///         fn len(&self) -> usize;
+///     }
+/// }
+///
+/// let (id, secret) = Token::new(1, String::from("secret")).into();
+/// assert_eq!(id, 1);
+/// assert_eq!(Name::new(secret).into_bytes(), b"secret");
+/// ```
+///
+/// Enums declare a whole set of states as linear. Each variant gets a constructor of the same
+/// name, `match_consume()` takes one closure per variant in the order of the variants.
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// pub struct Socket(u16);
+///
+/// linear! {
+///     /// Connection states.
+///     pub enum Conn {
+///         Closed,
+///         Open(Socket),
+///         Draining(Socket, usize),
+///     }
+/// }
+///
+/// let conn = Conn::Draining(Socket(80), 12);
+/// let pending = conn.match_consume(|| 0, |_| 0, |_, pending| pending);
+/// assert_eq!(pending, 12);
+/// ```
 #[macro_export]
 macro_rules! linear {
     (
         $(#[$($meta:tt)*])*
-        $vis:vis struct $name:ident<$t:ident, $u:ident>($inner:ty);
+        $vis:vis enum $name:ident {
+            $($(#[$($vmeta:tt)*])* $variant:ident $(($($field:ty),* $(,)?))?),* $(,)?
+        }
     ) => {
         $crate::deny_non_exhaustive!($(#[$($meta)*])*);
-        $(#[$($meta)*])*
-        #[derive(PartialEq, Eq, PartialOrd, Ord)]
-        #[must_use]
-        $vis struct $name<$t, $u>(
-            ::core::mem::ManuallyDrop<$inner>,
-            $crate::Linearity<$u>,
-        );
+        $crate::__linear_enum! {
+            @emit
+            $(#[$($meta)*])*
+            $vis enum $name {
+                $($(#[$($vmeta)*])* $variant ($($($field),*)?)),*
+            }
+        }
+    };
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis struct $name:ident<$t:ident, $u:ident>($inner:ident);
+    ) => {
+        $crate::deny_non_exhaustive!($(#[$($meta)*])*);
+        // The inner type must be the first parameter, otherwise this is a newtype that was
+        // meant to fix the unique type.
+        const _: () = {
+            #[allow(dead_code)]
+            const fn inner_is_first_parameter<$t>(inner: $inner) -> $t {
+                inner
+            }
+        };
+        $crate::__must_not_suspend! {
+            $(#[$($meta)*])*
+            #[derive(PartialEq, Eq, PartialOrd, Ord)]
+            #[must_use]
+            $vis struct $name<$t, $u>(
+                ::core::mem::ManuallyDrop<$inner>,
+                $crate::Linearity<$u>,
+            );
+        }
 
         /// Hashes only inner value.
         impl<$t: ::core::hash::Hash, $u> ::core::hash::Hash for $name<$t, $u> {
@@ -99,10 +929,9 @@ macro_rules! linear {
             }
         }
 
-        #[doc(hidden)]
         impl<$t, F: Fn()> $name<$t, $crate::UniqueType<F>> {
-            // to be called by the new_linear macro
-            #[doc(hidden)]
+            /// Constructs a new linear value branded with a `unique!()` type. Usually called by
+            /// the `new_linear!` macro.
             pub const fn new(inner: $inner, _: $crate::UniqueType<F>) -> Self {
                 $name(
                     ::core::mem::ManuallyDrop::new(inner),
@@ -115,25 +944,131 @@ macro_rules! linear {
             #[cfg(any(doc, feature = "semipure"))]
             /// Returns a reference to the inner value.
             ///
-            /// In a pure linear type-system even immutable access to the inner value is not available
-            /// because this may leak unwanted interior mutability or enable to clone the inner which
-            /// would be impure (in a linear type system). When one doesn't do either then the rust
-            /// type system/lifetimes are strong enough to be pure. This method is only available when
-            /// one defines the `semipure` feature. It's then the decision of the programmer not to use
-            /// any interior mutability/cloning or bend the rules and do something impure to make this
-            /// crate more convenient to use.
+            /// In a pure linear type-system even immutable access to the inner value is not available
+            /// because this may leak unwanted interior mutability or enable to clone the inner which
+            /// would be impure (in a linear type system). When one doesn't do either then the rust
+            /// type system/lifetimes are strong enough to be pure. This method is only available when
+            /// one defines the `semipure` feature. It's then the decision of the programmer not to use
+            /// any interior mutability/cloning or bend the rules and do something impure to make this
+            /// crate more convenient to use.
+            ///
+            /// # Safety
+            ///
+            /// The inner value must not be cloned or mutated through interior mutability.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let linear = new_linear!(123);
+            /// # #[cfg(feature = "semipure")]
+            /// assert_eq!(unsafe { linear.get_ref() }, &123);
+            /// # linear.into();
+            /// ```
+            pub unsafe fn get_ref(&self) -> &$inner {
+                &self.0
+            }
+
+            #[cfg(any(doc, feature = "semipure"))]
+            /// Calls a closure with a reference to the inner value and returns the linear type
+            /// unchanged. This allows to observe intermediate states in a chain of linear
+            /// evaluations.
+            ///
+            /// # Safety
+            ///
+            /// This is as impure as `get_ref()`. The closure must not clone the inner value or
+            /// mutate it through interior mutability. It is only available with the `semipure`
+            /// feature.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let linear = new_linear!(123);
+            /// # #[cfg(feature = "semipure")]
+            /// let linear = unsafe { linear.inspect(|x| println!("state: {x}")) };
+            /// assert_eq!(linear.map(|x| x + 1).into(), 124);
+            /// ```
+            pub unsafe fn inspect<F: FnOnce(&$inner)>(self, f: F) -> Self {
+                f(&self.0);
+                self
+            }
+
+            #[cfg(any(doc, feature = "semipure_mut"))]
+            /// Returns a mutable reference to the inner value.
+            ///
+            /// This allows in-place mutation of large values without moving them through `map()`.
+            /// Mutation does not create a new state, the linear type keeps its type and thus the
+            /// continuity of the state transitions is not tracked anymore.
+            ///
+            /// # Safety
+            ///
+            /// Beyond what applies to `get_ref()` the inner value must not be replaced by a value
+            /// made up from thin air which then substitutes the original one. This method is only
+            /// available when one defines the `semipure_mut` feature.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let mut linear = new_linear!(vec![0u8; 4096]);
+            /// # #[cfg(feature = "semipure_mut")]
+            /// unsafe { linear.get_mut()[0] = 1 };
+            /// # #[cfg(feature = "semipure_mut")]
+            /// assert_eq!(linear.into()[0], 1);
+            /// # #[cfg(not(feature = "semipure_mut"))]
+            /// # linear.destroy();
+            /// ```
+            pub unsafe fn get_mut(&mut self) -> &mut $inner {
+                &mut self.0
+            }
+
+            #[cfg(any(doc, feature = "semipure_mut"))]
+            /// Replaces the inner value with `value` and returns the old inner value.
+            ///
+            /// # Safety
+            ///
+            /// Same as `get_mut()`, the new value must be a legitimate substitute for the old one.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let mut slot = new_linear!(1);
+            /// # #[cfg(feature = "semipure_mut")]
+            /// assert_eq!(unsafe { slot.replace(2) }, 1);
+            /// # #[cfg(feature = "semipure_mut")]
+            /// assert_eq!(slot.into(), 2);
+            /// # #[cfg(not(feature = "semipure_mut"))]
+            /// # slot.destroy();
+            /// ```
+            pub unsafe fn replace(&mut self, value: $inner) -> $inner {
+                ::core::mem::replace(&mut *self.0, value)
+            }
+
+            #[cfg(any(doc, feature = "semipure_mut"))]
+            /// Swaps the inner values of two linear types of the same type, thus of the same
+            /// unique type.
+            ///
+            /// # Safety
+            ///
+            /// Same as `get_mut()`, each value must be a legitimate substitute for the other one.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// let linear = new_linear!(123);
-            /// # #[cfg(any(doc, feature = "semipure"))]
-            /// assert_eq!(linear.get_ref(), &123);
-            /// # linear.into();
+            /// let mut a = must_use(1);
+            /// let mut b = must_use(2);
+            /// # #[cfg(feature = "semipure_mut")]
+            /// unsafe { a.swap(&mut b) };
+            /// # #[cfg(feature = "semipure_mut")]
+            /// assert_eq!((a.into(), b.into()), (2, 1));
+            /// # #[cfg(not(feature = "semipure_mut"))]
+            /// # { a.destroy(); b.destroy(); }
             /// ```
-            pub unsafe fn get_ref(&self) -> &$inner {
-                &self.0
+            pub unsafe fn swap(&mut self, other: &mut Self) {
+                ::core::mem::swap(&mut *self.0, &mut *other.0);
             }
 
             /// Destructures the linear type and returns the inner type.  This must eventually be called on
@@ -184,412 +1119,438 @@ macro_rules! linear {
             /// assert_eq!(string.into(), "123");
             /// ```
             pub fn map<F: FnOnce($inner) -> R, R>(self, f: F) -> $name<R, Self> {
-                Self::transpose(f(self.into()))
-            }
-
-            const fn transpose<R>(r: R) -> $name<R, Self> {
-                $name(
-                    ::core::mem::ManuallyDrop::new(r),
-                    $crate::__linearity::<Self>(),
-                )
+                Self::transition(f(self.into()))
             }
-        }
 
-        /// Additional map methods for `Linear<Result<R,E>>`
-        impl<$t, E, $u> $name<::core::result::Result<$t, E>, $u> {
-            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<R,E>>` by applying a function
-            /// to the `Ok` value.  Retains a `Err` value.
+            /// Transforms one linear type to a linear `Result` by a fallible transition. This is
+            /// the same as `map()` but makes the following `Result` combinators infer their types
+            /// naturally.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # use std::io::Read;
-            /// let result = new_linear!(std::fs::File::open("Cargo.toml"));
-            /// let mapped = result.map_ok(|mut file| { let mut s = String::new(); file.read_to_string(&mut s)?; Ok(s)});
-            /// assert!(mapped.unwrap_ok().into().contains("linear_ty"));
+            /// let text = new_linear!("123");
+            /// let number = text.try_map(str::parse::<i32>);
+            /// assert_eq!(number.unwrap_ok().into(), 123);
             /// ```
-            pub fn map_ok<F: FnOnce($t) -> ::core::result::Result<R, E>, R>(
+            pub fn try_map<F: FnOnce($inner) -> ::core::result::Result<R, E>, R, E>(
                 self,
                 f: F,
             ) -> $name<::core::result::Result<R, E>, Self> {
-                match self.into() {
-                    Ok(t) => Self::transpose(f(t)),
-                    Err(e) => Self::transpose(Err(e)),
-                }
+                Self::transition(f(self.into()))
             }
 
-            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<T, R>>` by applying a function
-            /// to the `Err` value.  Retains a `Ok` value.
-            pub fn map_err<F: FnOnce(E) -> ::core::result::Result<$t, R>, R>(
+            /// Transforms one linear type to another linear type with a closure that itself
+            /// returns a linear type. Unlike `map()` this does not nest the result, the returned
+            /// linear value is consumed and its inner value is rewrapped.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// fn stringify(x: i32) -> MustUse<String> {
+            ///     must_use(x.to_string())
+            /// }
+            ///
+            /// let number = new_linear!(123);
+            /// let string = number.and_then(stringify);
+            /// assert_eq!(string.into(), "123");
+            /// ```
+            pub fn and_then<F: FnOnce($inner) -> $name<R, V>, R, V>(
                 self,
                 f: F,
-            ) -> $name<::core::result::Result<$t, R>, Self> {
-                match self.into() {
-                    Ok(t) => Self::transpose(Ok(t)),
-                    Err(e) => Self::transpose(f(e)),
-                }
+            ) -> $name<R, Self> {
+                Self::transition(f(self.into()).into())
             }
-        }
 
-        /// Additional `unwrap_ok()` method for `Linear<Result<T,E>>` where E is `Debug`.
-        impl<$t, E: ::core::fmt::Debug, $u> $name<::core::result::Result<$t, E>, $u> {
-            /// Unwraps a `Linear<Result<T,E>>` into a `Linear<T>`.
+            /// Applies a linear closure to the inner value. Both, the closure and the value are
+            /// consumed. This allows callbacks that must be called exactly once to be tracked as
+            /// linear values.
             ///
-            /// # Panics
+            /// # Example
             ///
-            /// When the value is an `Err`.
-            pub fn unwrap_ok(self) -> $name<$t, Self> {
-                $name::transpose(self.into().unwrap())
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let callback = new_linear!(|x: i32| x * 2);
+            /// let number = new_linear!(21);
+            /// let doubled = number.apply(callback);
+            /// assert_eq!(doubled.into(), 42);
+            /// ```
+            pub fn apply<F: FnOnce($inner) -> R, R, V>(self, f: $name<F, V>) -> $name<R, Self> {
+                Self::transition(f.into()(self.into()))
             }
-        }
 
-        /// Additional `unwrap_err()` method for `Linear<Result<T,E>>` where T is `Debug`.
-        impl<$t: ::core::fmt::Debug, E, $u> $name<::core::result::Result<$t, E>, $u> {
-            /// Unwraps a `Linear<Result<T,E>>` into a `Linear<E>`.
+            /// Consumes the linear type by applying a closure to the inner value. The result of
+            /// the closure is returned as is, not wrapped in a `Linear`. This is the terminal step
+            /// of a chain of linear evaluations.
             ///
-            /// # Panics
+            /// # Example
             ///
-            /// When the value is an `Ok`.
-            pub fn unwrap_err(self) -> $name<E, Self> {
-                $name::transpose(self.into().unwrap_err())
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let number = new_linear!(123);
+            /// let string = number.consume(|x| x.to_string());
+            /// assert_eq!(string, "123");
+            /// ```
+            pub fn consume<F: FnOnce($inner) -> R, R>(self, f: F) -> R {
+                f(self.into())
             }
-        }
 
-        /// Additional methods for `Linear<Option<T>>`, only fundamental methods are supported.
-        /// Anything beyond that needs to be handled manually.
-        impl<$t, $u> $name<::core::option::Option<$t>, $u> {
-            /// Transforms a `Linear<Option<T>>` into `Linear<Option<R>>` by applying a function
-            /// to the `Some` value.  Retains a `None` value.
+            /// Combines two linear types into a linear pair. Both values are consumed.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// let option = new_linear!(Some(123));
-            /// let mapped = option.map_some(|x| Some(x.to_string()));
-            /// assert_eq!(mapped.unwrap_some().into(), "123");
+            /// let socket = new_linear!("socket");
+            /// let token = new_linear!(42);
+            /// let pair = socket.zip(token);
+            /// assert_eq!(pair.into(), ("socket", 42));
             /// ```
-            pub fn map_some<F: FnOnce($t) -> ::core::option::Option<R>, R>(
-                self,
-                f: F,
-            ) -> $name<::core::option::Option<R>, Self> {
-                match self.into() {
-                    Some(t) => Self::transpose(f(t)),
-                    None => Self::transpose(None),
-                }
+            pub fn zip<B, V>(self, other: $name<B, V>) -> $name<($t, B), Self> {
+                Self::transition((self.into(), other.into()))
             }
 
-            /// Transforms a `Linear<Option<T>>` into `Linear<Option<T>>` by applying a function
-            /// to the `None` value.  Retains a `Some` value.
+            /// Combines two linear types into a new linear type by applying a closure to both
+            /// inner values. Both values are consumed.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// let option = new_linear!(None);
-            /// let mapped = option.or_else(|| Some(123));
-            /// assert_eq!(mapped.unwrap_some().into(), 123);
+            /// let request = new_linear!(String::from("GET /"));
+            /// let credentials = new_linear!("secret");
+            /// let signed = request.combine(credentials, |r, c| format!("{r} signed with {c}"));
+            /// assert_eq!(signed.into(), "GET / signed with secret");
             /// ```
-            pub fn or_else<F: FnOnce() -> ::core::option::Option<$t>>(
+            pub fn combine<B, V, F: FnOnce($inner, B) -> R, R>(
                 self,
+                other: $name<B, V>,
                 f: F,
-            ) -> $name<::core::option::Option<$t>, Self> {
-                match self.into() {
-                    inner @ Some(_) => Self::transpose(inner),
-                    None => Self::transpose(f()),
-                }
+            ) -> $name<R, Self> {
+                Self::transition(f(self.into(), other.into()))
             }
 
-            /// Unwraps a `Linear<Some<T>>` into a `Linear<T>`.
-            ///
-            /// # Panics
-            ///
-            /// When the value is `None`.
+            const fn transition<R>(r: R) -> $name<R, Self> {
+                $name(
+                    ::core::mem::ManuallyDrop::new(r),
+                    $crate::__linearity::<Self>(),
+                )
+            }
+        }
+
+        /// Additional `flatten()` method for nested `Linear<Linear<T>>`.
+        impl<$t, V, $u> $name<$name<$t, V>, $u> {
+            /// Collapses a `Linear<Linear<T>>` into a `Linear<T>`. The inner linear value is
+            /// consumed, only the outer linearity is carried on.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// let option = new_linear!(Some(123));
-            /// let unwrapped = option.unwrap_some();
-            /// assert_eq!(unwrapped.into(), 123);
+            /// let nested = new_linear!(new_linear!(123));
+            /// let flat = nested.flatten();
+            /// assert_eq!(flat.into(), 123);
             /// ```
-            pub fn unwrap_some(self) -> $name<$t, Self> {
-                $name::transpose(self.into().unwrap())
-            }
-        }
-    };
-    (
-        $(#[$($meta:tt)*])*
-        $vis:vis struct $name:ident($inner:ty);
-    ) => {
-        $crate::deny_non_exhaustive!($(#[$($meta)*])*);
-        $(#[$($meta)*])*
-        #[derive(PartialEq, Eq, PartialOrd, Ord)]
-        #[must_use]
-        $vis struct $name(
-            ::core::mem::ManuallyDrop<$inner>,
-            $crate::Linearity<$crate::UniqueType<fn()>>,
-        );
-
-        /// Hashes only inner value.
-        impl ::core::hash::Hash for $name
-        where
-            $inner: ::core::hash::Hash,
-        {
-            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                self.0.hash(state);
+            pub fn flatten(self) -> $name<$t, Self> {
+                Self::transition(self.into().into())
             }
         }
 
-        /// Custom debug outputing value and lifetime type
-        impl ::core::fmt::Debug for $name
-        where
-            $inner: ::core::fmt::Debug,
-        {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_tuple(stringify!($name))
-                    .field(&self.0)
-                    .field(&::core::any::type_name::<$crate::UniqueType<fn()>>())
-                    .finish()
+        /// Additional `unzip()` method for `Linear<(A, B)>`.
+        impl<A, B, $u> $name<(A, B), $u> {
+            /// Splits a linear pair into two linear values. Each of them must be consumed on its
+            /// own.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let pair = new_linear!(("socket", 42));
+            /// let (socket, token) = pair.unzip();
+            /// assert_eq!(socket.into(), "socket");
+            /// assert_eq!(token.into(), 42);
+            /// ```
+            pub fn unzip(self) -> ($name<A, Self>, $name<B, Self>) {
+                let (a, b) = self.into();
+                (Self::transition(a), Self::transition(b))
             }
         }
 
-        impl $name {
-            /// Constructs a new value with the fixed `U` type.
-            pub const fn new(inner: $inner) -> Self {
-                $name(
-                    ::core::mem::ManuallyDrop::new(inner),
-                    $crate::__linearity::<$crate::UniqueType<fn()>>(),
-                )
-            }
-
-            #[cfg(any(doc, feature = "semipure"))]
-            /// Returns a reference to the inner value.
-            pub unsafe fn get_ref(&self) -> &$inner {
-                &self.0
-            }
-
-            /// Destructures the linear type and returns the inner type.  This must eventually be called on
-            /// any linear type, failing to do so will panic when the linear type is dropped.
-            pub fn into(self) -> $inner {
-                let $name(t, linearity) = self;
-                ::core::mem::forget(linearity);
-                ::core::mem::ManuallyDrop::into_inner(t)
-            }
-
-            /// Consumes and destroys the wrapped value. This is like `into()` and them dropping
-            /// the returned value.
-            #[inline]
-            pub fn destroy(mut self) {
-                unsafe {
-                    ::core::mem::ManuallyDrop::drop(&mut self.0);
-                }
-                let $name(_, linearity) = self;
-                ::core::mem::forget(linearity);
+        /// Additional methods for linear arrays `Linear<[T; N]>`.
+        impl<$t, const N: usize, $u> $name<[$t; N], $u> {
+            /// Transforms a `Linear<[T; N]>` into `Linear<[R; N]>` by applying a function to each
+            /// element. Every element is consumed.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let permits = new_linear!([1, 2, 3]);
+            /// let doubled = permits.map_each(|x| x * 2);
+            /// assert_eq!(doubled.into(), [2, 4, 6]);
+            /// ```
+            pub fn map_each<F: FnMut($t) -> R, R>(self, f: F) -> $name<[R; N], Self> {
+                Self::transition(self.into().map(f))
             }
 
-            /// Transforms one linear type to another linear type. The inner value is passed to the
-            /// closure and the return value is wrapped in a `Linear`.
-            pub fn map<F: FnOnce($inner) -> R, R>(self, f: F) -> $crate::Linear<R, Self> {
-                $crate::__linear_from_parts::<R, Self>(f(self.into()))
+            /// Folds all elements of a `Linear<[T; N]>` into a `Linear<Acc>`. Every element is
+            /// consumed.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let permits = new_linear!([1, 2, 3]);
+            /// let sum = permits.fold(0, |acc, x| acc + x);
+            /// assert_eq!(sum.into(), 6);
+            /// ```
+            pub fn fold<F: FnMut(Acc, $t) -> Acc, Acc>(self, init: Acc, f: F) -> $name<Acc, Self> {
+                Self::transition(self.into().into_iter().fold(init, f))
             }
         }
 
-        // No Result/Option extensions for the fully concrete variant.
-    };
-    (
-        $(#[$($meta:tt)*])*
-        $vis:vis struct $name:ident<$t:ident>($inner:ty);
-    ) => {
-        $crate::deny_non_exhaustive!($(#[$($meta)*])*);
-        $(#[$($meta)*])*
-        #[derive(PartialEq, Eq, PartialOrd, Ord)]
-        #[must_use]
-        $vis struct $name<$t>(
-            ::core::mem::ManuallyDrop<$inner>,
-            $crate::Linearity<$crate::UniqueType<fn()>>,
+        $crate::__linear_split!(
+            $name, $u;
+            [T1 t1 T2 t2] T3 t3 T4 t4 T5 t5 T6 t6 T7 t7 T8 t8 T9 t9 T10 t10 T11 t11 T12 t12
         );
 
-        /// Hashes only inner value.
-        impl<$t: ::core::hash::Hash> ::core::hash::Hash for $name<$t> {
-            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                self.0.hash(state);
+        /// Additional map methods for `Linear<Result<R,E>>`
+        impl<$t, E, $u> $name<::core::result::Result<$t, E>, $u> {
+            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<R,E>>` by applying a function
+            /// to the `Ok` value.  Retains a `Err` value. This is the equivalent of
+            /// `Result::map()`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let result = new_linear!("123".parse::<i32>());
+            /// let mapped = result.map_ok(|x| x.to_string());
+            /// assert_eq!(mapped.unwrap_ok().into(), "123");
+            /// ```
+            pub fn map_ok<F: FnOnce($t) -> R, R>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<R, E>, Self> {
+                Self::transition(self.into().map(f))
             }
-        }
 
-        /// Custom debug outputing value and lifetime type
-        impl<$t: ::core::fmt::Debug> ::core::fmt::Debug for $name<$t> {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_tuple(stringify!($name))
-                    .field(&self.0)
-                    .field(&::core::any::type_name::<$crate::UniqueType<fn()>>())
-                    .finish()
+            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<R,E>>` by applying a fallible
+            /// function to the `Ok` value.  Retains a `Err` value. This is the equivalent of
+            /// `Result::and_then()`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// # use std::io::Read;
+            /// let result = new_linear!(std::fs::File::open("Cargo.toml"));
+            /// let mapped = result.and_then_ok(|mut file| { let mut s = String::new(); file.read_to_string(&mut s)?; Ok(s)});
+            /// assert!(mapped.unwrap_ok().into().contains("linear_ty"));
+            /// ```
+            pub fn and_then_ok<F: FnOnce($t) -> ::core::result::Result<R, E>, R>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<R, E>, Self> {
+                Self::transition(self.into().and_then(f))
             }
-        }
 
-        impl<$t> $name<$t> {
-            /// Constructs a new value with the fixed `U` type.
-            pub const fn new(inner: $inner) -> Self {
-                $name(
-                    ::core::mem::ManuallyDrop::new(inner),
-                    $crate::__linearity::<$crate::UniqueType<fn()>>(),
-                )
+            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<T, R>>` by applying a function
+            /// to the `Err` value.  Retains a `Ok` value.
+            #[deprecated(note = "use `or_else_err()` for recovery or `map_err_val()` to map the error")]
+            pub fn map_err<F: FnOnce(E) -> ::core::result::Result<$t, R>, R>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<$t, R>, Self> {
+                self.or_else_err(f)
             }
 
-            #[cfg(any(doc, feature = "semipure"))]
-            /// Returns a reference to the inner value.
+            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<T, R>>` by applying a function
+            /// to the `Err` value.  Retains a `Ok` value. This is the equivalent of
+            /// `Result::map_err()`.
             ///
-            /// In a pure linear type-system even immutable access to the inner value is not available
-            /// because this may leak unwanted interior mutability or enable to clone the inner which
-            /// would be impure (in a linear type system). When one doesn't do either then the rust
-            /// type system/lifetimes are strong enough to be pure. This method is only available when
-            /// one defines the `semipure` feature. It's then the decision of the programmer not to use
-            /// any interior mutability/cloning or bend the rules and do something impure to make this
-            /// crate more convenient to use.
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let result = new_linear!("abc".parse::<i32>());
+            /// let mapped = result.map_err_val(|e| e.to_string());
+            /// assert_eq!(mapped.unwrap_err().into(), "invalid digit found in string");
+            /// ```
+            pub fn map_err_val<F: FnOnce(E) -> R, R>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<$t, R>, Self> {
+                Self::transition(self.into().map_err(f))
+            }
+
+            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<T, R>>` by applying a function
+            /// to the `Err` value which may recover from the error.  Retains a `Ok` value.  This is
+            /// the equivalent of `Result::or_else()`.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let linear = Example::new(123);
-            /// # #[cfg(any(doc, feature = "semipure"))]
-            /// assert_eq!(linear.get_ref(), &123);
-            /// # linear.into();
+            /// let result = new_linear!("abc".parse::<i32>());
+            /// let recovered = result.or_else_err(|_| Ok::<_, ()>(0));
+            /// assert_eq!(recovered.unwrap_ok().into(), 0);
             /// ```
-            pub unsafe fn get_ref(&self) -> &$inner {
-                &self.0
+            pub fn or_else_err<F: FnOnce(E) -> ::core::result::Result<$t, R>, R>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<$t, R>, Self> {
+                Self::transition(self.into().or_else(f))
             }
 
-            /// Destructures the linear type and returns the inner type.  This must eventually be called on
-            /// any linear type, failing to do so will panic when the linear type is dropped.
+            /// Unwraps a `Linear<Result<T,E>>` into a `Linear<T>` computing a fallback value from
+            /// the `Err` value.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let linear = Example::new(123);
-            /// let inner = linear.into();
-            /// assert_eq!(inner, 123);
+            /// let result = new_linear!("abc".parse::<i32>());
+            /// let number = result.unwrap_ok_or_else(|_| 0);
+            /// assert_eq!(number.into(), 0);
             /// ```
-            pub fn into(self) -> $inner {
-                let $name(t, linearity) = self;
-                ::core::mem::forget(linearity);
-                ::core::mem::ManuallyDrop::into_inner(t)
+            pub fn unwrap_ok_or_else<F: FnOnce(E) -> $t>(self, f: F) -> $name<$t, Self> {
+                Self::transition(self.into().unwrap_or_else(f))
             }
 
-            /// Consumes and destroys the wrapped value. This is like `into()` and them dropping
-            /// the returned value.
+            /// Moves the linearity into the `Ok` or `Err` value of a `Linear<Result<T,E>>`.
+            /// Unlike `unwrap_ok()` and `unwrap_err()` this never panics and both arms remain
+            /// linear values that must be consumed.
+            ///
+            /// # Errors
+            ///
+            /// When the value is an `Err`, it is returned as linear `Err` value.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let linear = Example::new(123);
-            /// linear.destroy();
+            /// let result = new_linear!("123".parse::<i32>());
+            /// match result.branch() {
+            ///     Ok(number) => assert_eq!(number.into(), 123),
+            ///     Err(error) => panic!("{}", error.into()),
+            /// }
             /// ```
-            #[inline]
-            pub fn destroy(mut self) {
-                unsafe {
-                    ::core::mem::ManuallyDrop::drop(&mut self.0);
+            pub fn branch(self) -> ::core::result::Result<$name<$t, Self>, $name<E, Self>> {
+                match self.into() {
+                    Ok(t) => Ok(Self::transition(t)),
+                    Err(e) => Err(Self::transition(e)),
                 }
-                let $name(_, linearity) = self;
-                ::core::mem::forget(linearity);
             }
+        }
 
-            /// Transforms one linear type to another linear type. The inner value is passed to the
-            /// closure and the return value is wrapped in a `Linear`.
+        /// Additional `transpose()` method for `Linear<Option<Result<T,E>>>`.
+        impl<$t, E, $u> $name<::core::option::Option<::core::result::Result<$t, E>>, $u> {
+            /// Transposes a `Linear<Option<Result<T,E>>>` into a `Linear<Result<Option<T>,E>>`.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let number = Example::new(123);
-            /// let string = number.map(|x| x.to_string());
-            /// assert_eq!(string.into(), "123");
+            /// let option = new_linear!(Some("123".parse::<i32>()));
+            /// let result = option.transpose();
+            /// assert_eq!(result.unwrap_ok().unwrap_some().into(), 123);
             /// ```
-            pub fn map<F: FnOnce($inner) -> R, R>(self, f: F) -> $name<R> {
-                $name::<R>::transpose(f(self.into()))
-            }
-
-            const fn transpose<R>(r: R) -> $name<R> {
-                $name(
-                    ::core::mem::ManuallyDrop::new(r),
-                    $crate::__linearity::<$u_ty>(),
-                )
+            pub fn transpose(
+                self,
+            ) -> $name<::core::result::Result<::core::option::Option<$t>, E>, Self> {
+                Self::transition(self.into().transpose())
             }
         }
 
-        /// Additional map methods for `Linear<Result<R,E>>`
-        impl<$t, E> $name<::core::result::Result<$t, E>> {
-            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<R,E>>` by applying a function
-            /// to the `Ok` value.  Retains a `Err` value.
+        /// Additional `transpose()` method for `Linear<Result<Option<T>,E>>`.
+        impl<$t, E, $u> $name<::core::result::Result<::core::option::Option<$t>, E>, $u> {
+            /// Transposes a `Linear<Result<Option<T>,E>>` into a `Linear<Option<Result<T,E>>>`.
             ///
             /// # Example
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # use std::io::Read;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let result = Example::new(std::fs::File::open("Cargo.toml"));
-            /// let mapped = result.map_ok(|mut file| { let mut s = String::new(); file.read_to_string(&mut s)?; Ok(s)});
-            /// assert!(mapped.unwrap_ok().into().contains("linear_ty"));
+            /// let result = new_linear!(Ok::<_, ()>(Some(123)));
+            /// let option = result.transpose();
+            /// assert_eq!(option.unwrap_some().unwrap_ok().into(), 123);
             /// ```
-            pub fn map_ok<F: FnOnce($t) -> ::core::result::Result<R, E>, R>(
-                self,
-                f: F,
-            ) -> $name<::core::result::Result<R, E>> {
-                match self.into() {
-                    Ok(t) => $name::<::core::result::Result<R, E>>::transpose(f(t)),
-                    Err(e) => $name::<::core::result::Result<R, E>>::transpose(Err(e)),
-                }
-            }
-
-            /// Transforms a `Linear<Result<T,E>>` into `Linear<Result<T, R>>` by applying a function
-            /// to the `Err` value.  Retains a `Ok` value.
-            pub fn map_err<F: FnOnce(E) -> ::core::result::Result<$t, R>, R>(
+            pub fn transpose(
                 self,
-                f: F,
-            ) -> $name<::core::result::Result<$t, R>> {
-                match self.into() {
-                    Ok(t) => $name::<::core::result::Result<$t, R>>::transpose(Ok(t)),
-                    Err(e) => $name::<::core::result::Result<$t, R>>::transpose(f(e)),
-                }
+            ) -> $name<::core::option::Option<::core::result::Result<$t, E>>, Self> {
+                Self::transition(self.into().transpose())
             }
         }
 
         /// Additional `unwrap_ok()` method for `Linear<Result<T,E>>` where E is `Debug`.
-        impl<$t, E: ::core::fmt::Debug> $name<::core::result::Result<$t, E>> {
+        impl<$t, E: ::core::fmt::Debug, $u> $name<::core::result::Result<$t, E>, $u> {
             /// Unwraps a `Linear<Result<T,E>>` into a `Linear<T>`.
             ///
             /// # Panics
             ///
             /// When the value is an `Err`.
-            pub fn unwrap_ok(self) -> $name<$t> {
-                $name::<$t>::transpose(self.into().unwrap())
+            pub fn unwrap_ok(self) -> $name<$t, Self> {
+                $name::transition(self.into().unwrap())
+            }
+
+            /// Unwraps a `Linear<Result<T,E>>` into a `Linear<T>` with a custom panic message.
+            ///
+            /// # Panics
+            ///
+            /// When the value is an `Err`, with a panic message including the passed message and
+            /// the content of the `Err`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let result = new_linear!("123".parse::<i32>());
+            /// let number = result.expect_ok("number must be valid");
+            /// assert_eq!(number.into(), 123);
+            /// ```
+            #[track_caller]
+            pub fn expect_ok(self, msg: &str) -> $name<$t, Self> {
+                $name::transition(self.into().expect(msg))
             }
         }
 
         /// Additional `unwrap_err()` method for `Linear<Result<T,E>>` where T is `Debug`.
-        impl<$t: ::core::fmt::Debug, E> $name<::core::result::Result<$t, E>> {
+        impl<$t: ::core::fmt::Debug, E, $u> $name<::core::result::Result<$t, E>, $u> {
             /// Unwraps a `Linear<Result<T,E>>` into a `Linear<E>`.
             ///
             /// # Panics
             ///
             /// When the value is an `Ok`.
-            pub fn unwrap_err(self) -> $name<E> {
-                $name::<E>::transpose(self.into().unwrap_err())
+            pub fn unwrap_err(self) -> $name<E, Self> {
+                $name::transition(self.into().unwrap_err())
+            }
+
+            /// Unwraps a `Linear<Result<T,E>>` into a `Linear<E>` with a custom panic message.
+            ///
+            /// # Panics
+            ///
+            /// When the value is an `Ok`, with a panic message including the passed message and
+            /// the content of the `Ok`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let result = new_linear!("abc".parse::<i32>());
+            /// let error = result.expect_err("number must be invalid");
+            /// error.destroy();
+            /// ```
+            #[track_caller]
+            pub fn expect_err(self, msg: &str) -> $name<E, Self> {
+                $name::transition(self.into().expect_err(msg))
             }
         }
 
         /// Additional methods for `Linear<Option<T>>`, only fundamental methods are supported.
         /// Anything beyond that needs to be handled manually.
-        impl<$t> $name<::core::option::Option<$t>> {
+        impl<$t, $u> $name<::core::option::Option<$t>, $u> {
             /// Transforms a `Linear<Option<T>>` into `Linear<Option<R>>` by applying a function
             /// to the `Some` value.  Retains a `None` value.
             ///
@@ -597,18 +1558,17 @@ macro_rules! linear {
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let option = Example::new(Some(123));
+            /// let option = new_linear!(Some(123));
             /// let mapped = option.map_some(|x| Some(x.to_string()));
             /// assert_eq!(mapped.unwrap_some().into(), "123");
             /// ```
             pub fn map_some<F: FnOnce($t) -> ::core::option::Option<R>, R>(
                 self,
                 f: F,
-            ) -> $name<::core::option::Option<R>> {
+            ) -> $name<::core::option::Option<R>, Self> {
                 match self.into() {
-                    Some(t) => $name::<::core::option::Option<R>>::transpose(f(t)),
-                    None => $name::<::core::option::Option<R>>::transpose(None),
+                    Some(t) => Self::transition(f(t)),
+                    None => Self::transition(None),
                 }
             }
 
@@ -619,21 +1579,92 @@ macro_rules! linear {
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let option = Example::new(None);
+            /// let option = new_linear!(None);
             /// let mapped = option.or_else(|| Some(123));
             /// assert_eq!(mapped.unwrap_some().into(), 123);
             /// ```
             pub fn or_else<F: FnOnce() -> ::core::option::Option<$t>>(
                 self,
                 f: F,
-            ) -> $name<::core::option::Option<$t>> {
+            ) -> $name<::core::option::Option<$t>, Self> {
                 match self.into() {
-                    inner @ Some(_) => $name::<::core::option::Option<$t>>::transpose(inner),
-                    None => $name::<::core::option::Option<$t>>::transpose(f()),
+                    inner @ Some(_) => Self::transition(inner),
+                    None => Self::transition(f()),
                 }
             }
 
+            /// Combines two `Linear<Option<_>>` into a `Linear<Option<(A, B)>>`. The result is
+            /// `Some` only when both values are `Some`. Both linear values are consumed in any
+            /// case, when only one of them is `Some` its inner value is dropped.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let nonce = new_linear!(Some(1));
+            /// let key = new_linear!(Some("key"));
+            /// let both = nonce.zip_some(key);
+            /// assert_eq!(both.unwrap_some().into(), (1, "key"));
+            ///
+            /// let nonce = new_linear!(Some(1));
+            /// let key = new_linear!(None::<&str>);
+            /// assert!(nonce.zip_some(key).into().is_none());
+            /// ```
+            pub fn zip_some<B, V>(
+                self,
+                other: $name<::core::option::Option<B>, V>,
+            ) -> $name<::core::option::Option<($t, B)>, Self> {
+                Self::transition(self.into().zip(other.into()))
+            }
+
+            /// Transforms a `Linear<Option<T>>` into a `Linear<Result<T,E>>` mapping `None` to
+            /// `Err(err)`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(None::<i32>);
+            /// let result = option.ok_or("missing");
+            /// assert_eq!(result.unwrap_err().into(), "missing");
+            /// ```
+            pub fn ok_or<E>(self, err: E) -> $name<::core::result::Result<$t, E>, Self> {
+                Self::transition(self.into().ok_or(err))
+            }
+
+            /// Transforms a `Linear<Option<T>>` into a `Linear<Result<T,E>>` mapping `None` to
+            /// `Err(f())`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(Some(123));
+            /// let result = option.ok_or_else(|| "missing");
+            /// assert_eq!(result.unwrap_ok().into(), 123);
+            /// ```
+            pub fn ok_or_else<F: FnOnce() -> E, E>(
+                self,
+                f: F,
+            ) -> $name<::core::result::Result<$t, E>, Self> {
+                Self::transition(self.into().ok_or_else(f))
+            }
+
+            /// Unwraps a `Linear<Option<T>>` into a `Linear<T>` using `f()` as fallback value when
+            /// the value is `None`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(None);
+            /// let unwrapped = option.unwrap_some_or_else(|| 123);
+            /// assert_eq!(unwrapped.into(), 123);
+            /// ```
+            pub fn unwrap_some_or_else<F: FnOnce() -> $t>(self, f: F) -> $name<$t, Self> {
+                Self::transition(self.into().unwrap_or_else(f))
+            }
+
             /// Unwraps a `Linear<Some<T>>` into a `Linear<T>`.
             ///
             /// # Panics
@@ -644,16 +1675,96 @@ macro_rules! linear {
             ///
             /// ```rust
             /// # use linear_ty::*;
-            /// # linear! { pub struct Example<T>(T, UniqueType<fn()>); }
-            /// let option = Example::new(Some(123));
+            /// let option = new_linear!(Some(123));
             /// let unwrapped = option.unwrap_some();
             /// assert_eq!(unwrapped.into(), 123);
             /// ```
-            pub fn unwrap_some(self) -> $name<$t> {
-                $name::<$t>::transpose(self.into().unwrap())
+            pub fn unwrap_some(self) -> $name<$t, Self> {
+                $name::transition(self.into().unwrap())
+            }
+
+            /// Unwraps a `Linear<Some<T>>` into a `Linear<T>` with a custom panic message.
+            ///
+            /// # Panics
+            ///
+            /// When the value is `None`, with the passed message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(Some(123));
+            /// let unwrapped = option.expect_some("handshake must have completed");
+            /// assert_eq!(unwrapped.into(), 123);
+            /// ```
+            #[track_caller]
+            pub fn expect_some(self, msg: &str) -> $name<$t, Self> {
+                $name::transition(self.into().expect(msg))
+            }
+
+            /// Consumes a `Linear<Option<T>>` that is expected to be `None`.
+            ///
+            /// # Panics
+            ///
+            /// When the value is `Some`, with the passed message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(None::<i32>);
+            /// option.expect_none("no pending request expected");
+            /// ```
+            #[track_caller]
+            pub fn expect_none(self, msg: &str) {
+                if self.into().is_some() {
+                    panic!("{msg}");
+                }
+            }
+
+            /// Moves the linearity into the `Some` value of a `Linear<Option<T>>`. Unlike
+            /// `unwrap_some()` this never panics, a `None` value is consumed and returned as plain
+            /// `None`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use linear_ty::*;
+            /// let option = new_linear!(Some(123));
+            /// match option.branch() {
+            ///     Some(number) => assert_eq!(number.into(), 123),
+            ///     None => unreachable!(),
+            /// }
+            /// ```
+            pub fn branch(self) -> ::core::option::Option<$name<$t, Self>> {
+                self.into().map(Self::transition)
             }
         }
     };
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis struct $name:ident $(<$($g:ident),+ $(,)?>)? ($inner:ty) $($rest:tt)*
+    ) => {
+        $crate::deny_non_exhaustive!($(#[$($meta)*])*);
+        $crate::__linear_newtype! {
+            @start { $(#[$($meta)*])* $vis struct $name [$($($g)+)?] ($inner) ($inner) } $($rest)*
+        }
+    };
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis struct $name:ident $(<$($g:ident),+ $(,)?>)? {
+            $($(#[$($fmeta:tt)*])* $field:ident: $ty:ty),* $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::deny_non_exhaustive!($(#[$($meta)*])*);
+        $crate::__linear_newtype! {
+            @start {
+                $(#[$($meta)*])*
+                $vis struct $name [$($($g)+)?] (($($ty,)*)) {$($field: $ty),*}
+            } ; $($rest)*
+        }
+    };
 }
 
 linear! {
@@ -678,15 +1789,77 @@ pub fn must_use<T>(val: T) -> MustUse<T> {
     MustUse::new(val, unique!())
 }
 
-/// A marker struct that is constructed with unique closure types.
+/// Calls `f` and wraps its result in a `MustUse<T>`. Used by `#[must_consume]`, taking the
+/// closure as `FnOnce` lets the body return borrows of captured references.
+#[doc(hidden)]
+pub fn __must_consume<T>(f: impl FnOnce() -> T) -> MustUse<T> {
+    must_use(f())
+}
+
+/// Wraps a value in a `MustUse<T>`, same as `must_use()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let linear: MustUse<_> = 123.into();
+/// assert_eq!(linear.into(), 123);
+/// ```
+impl<T> From<T> for MustUse<T> {
+    fn from(val: T) -> Self {
+        must_use(val)
+    }
+}
+
+/// Extension trait to start a chain of linear evaluations from any value.
+pub trait LinearExt: Sized {
+    /// Wraps the value in a `MustUse<Self>`, same as `must_use()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let string = 123.linear().map(|x| x.to_string());
+    /// assert_eq!(string.into(), "123");
+    /// ```
+    fn linear(self) -> MustUse<Self>;
+}
+
+impl<T> LinearExt for T {
+    fn linear(self) -> MustUse<Self> {
+        must_use(self)
+    }
+}
+
+/// A marker struct that is constructed with unique closure types, see `unique!()`.
 pub struct UniqueType<F: Fn()>(pub ManuallyDrop<F>);
 
-// Returns a value with a unique type for every call.
-#[doc(hidden)]
+/// Returns a zero sized brand with a unique type for every call site. Values branded at
+/// different call sites are of distinct types and can never be confused.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let brand = unique!();
+/// assert_eq!(core::mem::size_of_val(&brand), 0);
+/// let linear = Linear::new("token", brand);
+/// assert_eq!(linear.into(), "token");
+/// ```
+///
+/// Brands of two call sites differ even for the same inner type:
+///
+/// ```compile_fail
+/// # use linear_ty::*;
+/// let mut a = Linear::new(1, unique!());
+/// let b = Linear::new(1, unique!());
+/// a.into();
+/// a = b;
+/// ```
 #[macro_export]
 macro_rules! unique {
     () => {
-        $crate::UniqueType(std::mem::ManuallyDrop::new(|| ()))
+        $crate::UniqueType(::core::mem::ManuallyDrop::new(|| ()))
     };
 }
 
@@ -733,17 +1906,23 @@ impl Drop for NoDrop {
             panic!("linear type dropped");
         }
     }
-    #[cfg(not(test))]
+    #[cfg(all(not(test), feature = "std"))]
     fn drop(&mut self) {
         // be nice in debug builds and tell why we are aborting
         #[cfg(debug_assertions)]
         eprintln!("linear type dropped");
         std::process::abort();
     }
+    // Without `std` there is no abort, the panic handler of the target takes over.
+    #[cfg(not(any(test, feature = "std")))]
+    fn drop(&mut self) {
+        panic!("linear type dropped");
+    }
 }
 
+#[cfg(feature = "std")]
 linear! {
-    /// Linear string.
+    /// Linear string, available with the `std` feature.
     pub struct LinearString(String);
 }
 
@@ -751,15 +1930,24 @@ linear! {
 mod tests {
     use static_assertions::assert_not_impl_any;
 
+    #[cfg(feature = "std")]
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "linear type dropped")]
     fn panics() {
         let _ = crate::LinearString::new("Hello".to_string());
     }
 
+    #[cfg(feature = "std")]
     assert_not_impl_any!(crate::LinearString: Clone, Copy);
     assert_not_impl_any!(crate::Linear<u8, crate::UniqueType<fn()>>: Clone, Copy);
 
+    #[test]
+    fn unique_is_zero_sized() {
+        let linear = crate::Linear::new(1u8, unique!());
+        assert_eq!(core::mem::size_of_val(&linear), 1);
+        linear.destroy();
+    }
+
     linear!(
         pub struct Foo(u32);
     );
@@ -769,6 +1957,186 @@ mod tests {
         Foo::new(42).destroy();
     }
 
+    linear! {
+        /// Generic newtype with a `where` clause.
+        pub struct Pair<A, B, C>((A, B, C)) where A: Copy, B: Default;
+    }
+
+    #[test]
+    fn generic_newtype() {
+        let pair = Pair::new((1, String::new(), "c"));
+        assert_eq!(pair.map(|(a, b, c)| (a, b.len(), c)).into(), (1, 0, "c"));
+        Pair::new((1, 2, 3)).destroy();
+        let sum = Pair::new((1, 2, 3)).and_then(|(a, b, c)| crate::must_use(a + b + c));
+        assert_eq!(sum.into(), 6);
+        assert_eq!(Pair::new((1, 2, 3)).consume(|(a, _, _)| a), 1);
+    }
+
+    linear! {
+        /// Two type parameters without a `where` clause.
+        pub struct Both<A, B>((A, B));
+    }
+
+    #[test]
+    fn two_parameter_newtype() {
+        let (a, b) = (Both::new((1, "b")), Both::new((1, "b")));
+        assert_eq!(a, b);
+        assert!(format!("{a:?}").starts_with("Both("));
+        assert_eq!(a.map(|(n, _)| n).into(), 1);
+        b.destroy();
+    }
+
+    /// Socket stand in.
+    pub struct Socket(u16);
+
+    linear! {
+        /// Connection states.
+        pub enum Conn {
+            /// Closed connection.
+            Closed,
+            /// Open connection.
+            Open(Socket),
+            /// Draining connection with pending bytes.
+            Draining(Socket, usize),
+        }
+    }
+
+    #[test]
+    fn linear_enum() {
+        let port =
+            |conn: Conn| conn.match_consume(|| 0, |Socket(port)| port, |Socket(port), _| port);
+        assert_eq!(port(Conn::Closed()), 0);
+        assert_eq!(port(Conn::Open(Socket(80))), 80);
+        assert_eq!(port(Conn::Draining(Socket(443), 1)), 443);
+        Conn::Closed().destroy();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn linear_enum_dropped() {
+        let _ = Conn::Open(Socket(80));
+    }
+
+    linear! {
+        /// Named fields.
+        pub struct Token {
+            id: u64,
+            secret: String,
+        }
+    }
+
+    linear! {
+        /// Delegating methods of the inner value.
+        pub struct Name(String);
+
+        #[delegate]
+        impl {
+            fn into_bytes(self) -> Vec<u8>;
+            fn len(&self) -> usize;
+        }
+    }
+
+    #[test]
+    fn named_fields() {
+        let token = Token::new(7, String::from("secret"));
+        let (id, secret) = token.into();
+        assert_eq!((id, secret.as_str()), (7, "secret"));
+        Token::new(1, String::new()).map(|(id, _)| id).destroy();
+        Token::new(2, String::new()).destroy();
+        assert_eq!(Token::new(1, String::new()).consume(|(_, s)| s), "");
+        assert_eq!(
+            Token::new(1, String::new())
+                .and_then(|(id, _)| crate::must_use(id))
+                .into(),
+            1
+        );
+    }
+
+    #[test]
+    fn delegate() {
+        assert_eq!(Name::new(String::from("ab")).into_bytes(), b"ab");
+        Name::new(String::new()).destroy();
+        assert_eq!(Name::new(String::from("ab")).consume(|s| s.len()), 2);
+        let len = Name::new(String::from("ab")).and_then(|s| crate::must_use(s.len()));
+        assert_eq!(len.into(), 2);
+        assert_eq!(Name::new(String::from("ab")).map(|s| s + "c").into(), "abc");
+    }
+
+    #[test]
+    fn foo_map() {
+        assert_eq!(Foo::new(42).map(|x| x + 1).into(), 43);
+        assert_eq!(Foo::new(42).and_then(|x| crate::must_use(x + 1)).into(), 43);
+        assert_eq!(Foo::new(42).consume(|x| x + 1), 43);
+    }
+
+    #[test]
+    fn flatten() {
+        let nested = new_linear!(new_linear!(42));
+        assert_eq!(nested.flatten().into(), 42);
+    }
+
+    #[test]
+    fn flatten_map() {
+        let nested = new_linear!(7).map(|x| new_linear!(x * 6));
+        assert_eq!(nested.flatten().map(|x| x + 1).into(), 43);
+    }
+
+    #[test]
+    fn zip_unzip() {
+        let pair = new_linear!(1).zip(new_linear!("one"));
+        let (number, name) = pair.unzip();
+        assert_eq!(number.into(), 1);
+        assert_eq!(name.into(), "one");
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn unzip_drop_half() {
+        let (number, _) = new_linear!((1, 2)).unzip();
+        number.destroy();
+    }
+
+    #[test]
+    fn split() {
+        let (a, b, c) = new_linear!((1, "two", 3.0)).split();
+        assert_eq!(a.into(), 1);
+        assert_eq!(b.into(), "two");
+        assert!((c.into() - 3.0f64).abs() < f64::EPSILON);
+
+        let parts = new_linear!((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)).split();
+        assert_eq!(parts.0.into() + parts.11.into(), 13);
+        parts.1.destroy();
+        parts.2.destroy();
+        parts.3.destroy();
+        parts.4.destroy();
+        parts.5.destroy();
+        parts.6.destroy();
+        parts.7.destroy();
+        parts.8.destroy();
+        parts.9.destroy();
+        parts.10.destroy();
+    }
+
+    #[test]
+    fn branch_result() {
+        let result: Result<u8, &str> = Err("failed");
+        match new_linear!(result).branch() {
+            Ok(_) => unreachable!(),
+            Err(error) => assert_eq!(error.into(), "failed"),
+        }
+    }
+
+    #[test]
+    fn branch_option() {
+        assert!(new_linear!(None::<u8>).branch().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "handshake must have completed")]
+    fn expect_some_message() {
+        let _ = new_linear!(None::<u8>).expect_some("handshake must have completed");
+    }
+
     struct Abc {
         a: String,
         b: u8,
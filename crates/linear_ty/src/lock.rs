@@ -0,0 +1,216 @@
+use crate::{__linear_from_parts, MustUse};
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A mutex whose guard is linear, a critical section ends at an explicit `unlock()`. Holding
+/// the lock across an `.await` or a long call becomes visible where the guard is consumed.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let counter = LinearMutex::new(0);
+/// let mut guard = counter.lock();
+/// *guard.get_mut() += 1;
+/// guard.unlock();
+/// assert_eq!(counter.lock().unlock_map(|count| *count), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct LinearMutex<T>(Mutex<T>);
+
+/// Linear guard of a `LinearMutex`, it must be `unlock()`ed.
+#[must_use]
+#[cfg_attr(
+    feature = "nightly",
+    must_not_suspend = "unlock the guard before the `.await`"
+)]
+pub struct LinearMutexGuard<'a, T>(MustUse<MutexGuard<'a, T>>);
+
+impl<T> LinearMutex<T> {
+    /// Creates an unlocked mutex holding `value`.
+    pub const fn new(value: T) -> Self {
+        Self(Mutex::new(value))
+    }
+
+    /// Locks the mutex, blocking until it is available.
+    pub fn lock(&self) -> LinearMutexGuard<'_, T> {
+        // Panicking while holding a linear guard is a linearity violation on its own, a
+        // poisoned lock is not reported twice.
+        LinearMutexGuard(__linear_from_parts(
+            self.0.lock().unwrap_or_else(PoisonError::into_inner),
+        ))
+    }
+
+    /// Consumes the mutex and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> LinearMutexGuard<'_, T> {
+    /// Returns a reference to the guarded value.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.0.0
+    }
+
+    /// Returns a mutable reference to the guarded value.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0.0
+    }
+
+    /// Unlocks the mutex.
+    pub fn unlock(self) {
+        drop(self.0.into());
+    }
+
+    /// Applies a closure to the guarded value and unlocks the mutex afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let queue = LinearMutex::new(vec![1, 2]);
+    /// assert_eq!(queue.lock().unlock_map(Vec::pop), Some(2));
+    /// ```
+    pub fn unlock_map<F: FnOnce(&mut T) -> R, R>(self, f: F) -> R {
+        let mut guard = self.0.into();
+        f(&mut guard)
+    }
+}
+
+/// A reader-writer lock whose guards are linear, like `LinearMutex`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let config = LinearRwLock::new(String::from("a"));
+/// let first = config.read();
+/// let second = config.read();
+/// assert_eq!(first.get(), second.get());
+/// first.unlock();
+/// second.unlock();
+/// config.write().unlock_map(|config| config.push('b'));
+/// assert_eq!(config.read().unlock_map(String::len), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct LinearRwLock<T>(RwLock<T>);
+
+/// Linear shared guard of a `LinearRwLock`, it must be `unlock()`ed.
+#[must_use]
+#[cfg_attr(
+    feature = "nightly",
+    must_not_suspend = "unlock the guard before the `.await`"
+)]
+pub struct LinearReadGuard<'a, T>(MustUse<RwLockReadGuard<'a, T>>);
+
+/// Linear exclusive guard of a `LinearRwLock`, it must be `unlock()`ed.
+#[must_use]
+#[cfg_attr(
+    feature = "nightly",
+    must_not_suspend = "unlock the guard before the `.await`"
+)]
+pub struct LinearWriteGuard<'a, T>(MustUse<RwLockWriteGuard<'a, T>>);
+
+impl<T> LinearRwLock<T> {
+    /// Creates an unlocked lock holding `value`.
+    pub const fn new(value: T) -> Self {
+        Self(RwLock::new(value))
+    }
+
+    /// Locks for shared access, blocking while a writer holds the lock.
+    pub fn read(&self) -> LinearReadGuard<'_, T> {
+        // A poisoned lock is not reported twice, see `LinearMutex::lock()`.
+        LinearReadGuard(__linear_from_parts(
+            self.0.read().unwrap_or_else(PoisonError::into_inner),
+        ))
+    }
+
+    /// Locks for exclusive access, blocking while any other guard holds the lock.
+    pub fn write(&self) -> LinearWriteGuard<'_, T> {
+        // A poisoned lock is not reported twice, see `LinearMutex::lock()`.
+        LinearWriteGuard(__linear_from_parts(
+            self.0.write().unwrap_or_else(PoisonError::into_inner),
+        ))
+    }
+
+    /// Consumes the lock and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> LinearReadGuard<'_, T> {
+    /// Returns a reference to the guarded value.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.0.0
+    }
+
+    /// Unlocks the shared access.
+    pub fn unlock(self) {
+        drop(self.0.into());
+    }
+
+    /// Applies a closure to the guarded value and unlocks afterwards.
+    pub fn unlock_map<F: FnOnce(&T) -> R, R>(self, f: F) -> R {
+        f(&self.0.into())
+    }
+}
+
+impl<T> LinearWriteGuard<'_, T> {
+    /// Returns a reference to the guarded value.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.0.0
+    }
+
+    /// Returns a mutable reference to the guarded value.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0.0
+    }
+
+    /// Unlocks the exclusive access.
+    pub fn unlock(self) {
+        drop(self.0.into());
+    }
+
+    /// Applies a closure to the guarded value and unlocks afterwards.
+    pub fn unlock_map<F: FnOnce(&mut T) -> R, R>(self, f: F) -> R {
+        f(&mut self.0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearMutex, LinearRwLock};
+
+    #[test]
+    fn relock() {
+        let mutex = LinearMutex::new(1);
+        mutex.lock().unlock_map(|x| *x += 1);
+        let guard = mutex.lock();
+        assert_eq!(*guard.get(), 2);
+        guard.unlock();
+        assert_eq!(mutex.into_inner(), 2);
+    }
+
+    #[test]
+    fn write_after_reads() {
+        let lock = LinearRwLock::new(Vec::new());
+        lock.read().unlock();
+        let mut writer = lock.write();
+        writer.get_mut().push(1);
+        writer.unlock();
+        assert_eq!(lock.into_inner(), [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn guard_dropped() {
+        let mutex = LinearMutex::new(());
+        let _ = mutex.lock();
+    }
+}
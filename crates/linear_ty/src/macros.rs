@@ -0,0 +1,236 @@
+/// Destructures a linear value holding an enum and matches on it. The result of every arm is
+/// wrapped in a linear value again, tagged with the type of the matched value.
+///
+/// The arms are evaluated within a closure, thus `return` and `?` apply to the arm itself.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// enum Conn {
+///     Closed,
+///     Open(u16),
+/// }
+///
+/// let conn = new_linear!(Conn::Open(8080));
+/// let port = match_consume!(conn, {
+///     Conn::Open(port) => Some(port),
+///     Conn::Closed => None,
+/// });
+/// assert_eq!(port.unwrap_some().into(), 8080);
+/// ```
+#[macro_export]
+macro_rules! match_consume {
+    ($linear:expr, { $($arms:tt)* }) => {
+        $linear.map(|inner| match inner { $($arms)* })
+    };
+}
+
+/// Destructures a linear value and binds the inner value to an irrefutable pattern in the
+/// current scope. Refutable patterns are rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// struct Filename(&'static str);
+///
+/// let filename = new_linear!(Filename("README.md"));
+/// destructure!(filename => Filename(name));
+/// assert_eq!(name, "README.md");
+/// ```
+#[macro_export]
+macro_rules! destructure {
+    ($linear:expr => $pat:pat) => {
+        let $pat = $linear.into();
+    };
+}
+
+/// Builds a chain of state transitions starting from a plain value. Each stage is applied with
+/// `map()`, once a stage returned a `Result` the following stages are applied to the `Ok` value
+/// with `and_then_ok()` and must return a `Result` with the same error type.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// fn trim(s: &str) -> &str {
+///     s.trim()
+/// }
+///
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// fn double(x: i32) -> Result<i32, std::num::ParseIntError> {
+///     Ok(x * 2)
+/// }
+///
+/// let number = chain!(" 21 " => trim => parse => double);
+/// assert_eq!(number.unwrap_ok().into(), 42);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    ($start:expr $(=> $stage:expr)* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{__ChainOk as _, __ChainPlain as _};
+        let linear = $crate::new_linear!($start);
+        $(let linear = (&$crate::__Chain::new(linear)).__chain_step($stage);)*
+        linear
+    }};
+}
+
+/// Holds a linear value for one stage of the `chain!` macro.
+#[doc(hidden)]
+pub struct __Chain<T>(core::cell::Cell<Option<T>>);
+
+impl<T> __Chain<T> {
+    #[doc(hidden)]
+    pub const fn new(linear: T) -> Self {
+        Self(core::cell::Cell::new(Some(linear)))
+    }
+
+    fn take(&self) -> T {
+        self.0.take().expect("chain stage applied twice")
+    }
+}
+
+/// Stage on a linear `Result`, preferred by method resolution.
+#[doc(hidden)]
+pub trait __ChainOk<T, E, U> {
+    fn __chain_step<F: FnOnce(T) -> Result<R, E>, R>(
+        &self,
+        f: F,
+    ) -> crate::Linear<Result<R, E>, crate::Linear<Result<T, E>, U>>;
+}
+
+impl<T, E, U> __ChainOk<T, E, U> for __Chain<crate::Linear<Result<T, E>, U>> {
+    fn __chain_step<F: FnOnce(T) -> Result<R, E>, R>(
+        &self,
+        f: F,
+    ) -> crate::Linear<Result<R, E>, crate::Linear<Result<T, E>, U>> {
+        self.take().and_then_ok(f)
+    }
+}
+
+/// Stage on any other linear value, only reached through autoref.
+#[doc(hidden)]
+pub trait __ChainPlain<T, U> {
+    fn __chain_step<F: FnOnce(T) -> R, R>(&self, f: F) -> crate::Linear<R, crate::Linear<T, U>>;
+}
+
+impl<T, U> __ChainPlain<T, U> for &__Chain<crate::Linear<T, U>> {
+    fn __chain_step<F: FnOnce(T) -> R, R>(&self, f: F) -> crate::Linear<R, crate::Linear<T, U>> {
+        self.take().map(f)
+    }
+}
+
+/// Extracts the `Ok` value of a `Linear<Result<T,E>>` as `Linear<T>` or returns early with the
+/// `Err` value from a function returning a `Linear<Result<_,F>>` where `F: From<E>`. This is the
+/// stable counterpart of the `?` operator on linear results.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// fn parse_and_double(text: MustUse<&str>) -> MustUse<Result<i32, std::num::ParseIntError>> {
+///     let number = linear_try!(text.try_map(str::parse::<i32>));
+///     must_use(Ok(number.into() * 2))
+/// }
+///
+/// assert_eq!(parse_and_double(must_use("21")).unwrap_ok().into(), 42);
+/// parse_and_double(must_use("x")).unwrap_err().destroy();
+/// ```
+#[macro_export]
+macro_rules! linear_try {
+    ($linear:expr $(,)?) => {
+        match $linear.branch() {
+            ::core::result::Result::Ok(ok) => ok,
+            ::core::result::Result::Err(err) => {
+                return $crate::__LinearTry::__from_linear_err(err);
+            }
+        }
+    };
+}
+
+/// Builds the early returned linear result of `linear_try!`.
+#[doc(hidden)]
+pub trait __LinearTry<E, V> {
+    fn __from_linear_err(err: crate::Linear<E, V>) -> Self;
+}
+
+impl<T, E, F: From<E>, U, V> __LinearTry<E, V> for crate::Linear<Result<T, F>, U> {
+    fn __from_linear_err(err: crate::Linear<E, V>) -> Self {
+        crate::__linear_from_parts(Err(From::from(err.into())))
+    }
+}
+
+/// Destructures several linear values at once and evaluates a closure like expression with all
+/// inner values. The result is wrapped in a linear value again, tagged with the types of all
+/// consumed values. Up to twelve values are supported.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let socket = new_linear!("socket");
+/// let token = new_linear!(42);
+/// let request = new_linear!(String::from("GET /"));
+/// let sent = consume_all!(socket, token, request => |socket, token, request| {
+///     format!("{request} on {socket} with {token}")
+/// });
+/// assert_eq!(sent.into(), "GET / on socket with 42");
+/// ```
+#[macro_export]
+macro_rules! consume_all {
+    ($($linear:expr),+ $(,)? => |$($arg:pat_param),+ $(,)?| $body:expr) => {
+        $crate::__consume_all(($($linear,)+), |($($arg,)+)| $body)
+    };
+}
+
+/// Destructures a tuple of linear values into a tuple of their inner values.
+#[doc(hidden)]
+pub trait __IntoInners {
+    type Inners;
+
+    fn __into_inners(self) -> Self::Inners;
+}
+
+macro_rules! impl_into_inners {
+    ($(($($t:ident $u:ident $v:ident),+))+) => {
+        $(
+            impl<$($t, $u),+> __IntoInners for ($(crate::Linear<$t, $u>,)+) {
+                type Inners = ($($t,)+);
+
+                fn __into_inners(self) -> Self::Inners {
+                    let ($($v,)+) = self;
+                    ($($v.into(),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_into_inners! {
+    (T1 U1 v1)
+    (T1 U1 v1, T2 U2 v2)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7, T8 U8 v8)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7, T8 U8 v8, T9 U9 v9)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7, T8 U8 v8, T9 U9 v9, T10 U10 v10)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7, T8 U8 v8, T9 U9 v9, T10 U10 v10, T11 U11 v11)
+    (T1 U1 v1, T2 U2 v2, T3 U3 v3, T4 U4 v4, T5 U5 v5, T6 U6 v6, T7 U7 v7, T8 U8 v8, T9 U9 v9, T10 U10 v10, T11 U11 v11, T12 U12 v12)
+}
+
+/// Backs the `consume_all!` macro.
+#[doc(hidden)]
+pub fn __consume_all<L: __IntoInners, F: FnOnce(L::Inners) -> R, R>(
+    linears: L,
+    f: F,
+) -> crate::Linear<R, L> {
+    crate::__linear_from_parts(f(linears.__into_inners()))
+}
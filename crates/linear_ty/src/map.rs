@@ -0,0 +1,140 @@
+use crate::{__linear_from_parts, Linear};
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A map of linear values. Inserting takes `Linear<V>` values and removing returns them as
+/// `Linear<V>` again, the map itself is linear and can only be finished with `finish_empty()`
+/// once every entry was removed. Fits registries of in-flight requests where every entry must
+/// be completed or cancelled explicitly.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut in_flight = LinearMap::new();
+/// in_flight.insert(1, must_use("request 1"));
+/// in_flight.insert(2, must_use("request 2"));
+/// assert_eq!(in_flight.remove(&2).unwrap().into(), "request 2");
+/// in_flight.remove(&1).unwrap().destroy();
+/// assert!(in_flight.finish_empty().is_ok());
+/// ```
+#[must_use]
+pub struct LinearMap<K, V, U>(Linear<HashMap<K, V>, U>);
+
+impl<K, V, U> LinearMap<K, V, U> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self(__linear_from_parts(HashMap::new()))
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.0.len()
+    }
+
+    /// Returns whether the map has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.0.is_empty()
+    }
+
+    /// Returns an iterator over the keys of the entries.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.0.0.keys()
+    }
+
+    /// Finishes an empty map.
+    ///
+    /// # Errors
+    ///
+    /// When entries are left the map is returned as `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut map = LinearMap::new();
+    /// map.insert("key", must_use(1));
+    /// let mut map = map.finish_empty().err().unwrap();
+    /// map.remove("key").unwrap().destroy();
+    /// assert!(map.finish_empty().is_ok());
+    /// ```
+    pub fn finish_empty(self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.0.destroy();
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, U> LinearMap<K, V, U> {
+    /// Inserts a linear value, a value replaced under the same key is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut map = LinearMap::new();
+    /// assert!(map.insert(1, must_use("a")).is_none());
+    /// assert_eq!(map.insert(1, must_use("b")).unwrap().into(), "a");
+    /// # map.remove(&1).unwrap().destroy();
+    /// # map.finish_empty().ok().unwrap();
+    /// ```
+    pub fn insert(&mut self, key: K, value: Linear<V, U>) -> Option<Linear<V, U>> {
+        self.0.0.insert(key, value.into()).map(__linear_from_parts)
+    }
+
+    /// Removes an entry and returns its value.
+    pub fn remove<Q: Eq + Hash + ?Sized>(&mut self, key: &Q) -> Option<Linear<V, U>>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.0.remove(key).map(__linear_from_parts)
+    }
+
+    /// Returns whether the map has an entry for the key.
+    pub fn contains_key<Q: Eq + Hash + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.0.0.contains_key(key)
+    }
+}
+
+impl<K, V, U> Default for LinearMap<K, V, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearMap;
+    use crate::must_use;
+
+    #[test]
+    fn registry() {
+        let mut map = LinearMap::new();
+        for id in 0..3 {
+            map.insert(id, must_use(format!("request {id}")));
+        }
+        assert!(map.contains_key(&1));
+        assert_eq!(map.keys().count(), 3);
+        let completed: Vec<_> = (0..3)
+            .filter_map(|id| map.remove(&id))
+            .map(crate::Linear::into)
+            .collect();
+        assert_eq!(completed.len(), 3);
+        map.finish_empty().ok().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearMap::<u8, u8, ()>::new();
+    }
+}
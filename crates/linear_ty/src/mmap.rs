@@ -0,0 +1,102 @@
+use crate::{__linear_from_parts, MustUse};
+use memmap2::MmapMut;
+use std::fs::File;
+use std::io;
+
+/// A writable memory map which must be flushed and unmapped explicitly. Dropping a `MmapMut`
+/// unmaps it without reporting whether the changes reached the file, `flush_and_unmap()`
+/// returns the error.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::mmap::LinearMmapMut;
+///
+/// let path = std::env::temp_dir().join("linear_ty_mmap_example");
+/// let file = std::fs::OpenOptions::new()
+///     .read(true)
+///     .write(true)
+///     .create(true)
+///     .truncate(true)
+///     .open(&path)?;
+/// file.set_len(6)?;
+/// // SAFETY: the file is not modified by anyone else while it is mapped.
+/// let mut map = unsafe { LinearMmapMut::map_mut(&file)? };
+/// map.get_mut().copy_from_slice(b"mapped");
+/// map.flush_and_unmap()?;
+/// assert_eq!(std::fs::read(&path)?, b"mapped");
+/// # std::fs::remove_file(path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearMmapMut(MustUse<MmapMut>);
+
+impl LinearMmapMut {
+    /// Wraps a writable memory map.
+    pub const fn new(map: MmapMut) -> Self {
+        Self(__linear_from_parts(map))
+    }
+
+    /// Maps a whole file writable, like `MmapMut::map_mut()`.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be mapped.
+    ///
+    /// # Safety
+    ///
+    /// Like `MmapMut::map_mut()`, the file must not be modified or truncated by others while
+    /// it is mapped.
+    pub unsafe fn map_mut(file: &File) -> io::Result<Self> {
+        // SAFETY: the guarantees are passed on to the caller.
+        unsafe { MmapMut::map_mut(file) }.map(Self::new)
+    }
+
+    /// Returns the mapped memory.
+    #[must_use]
+    pub fn get(&self) -> &[u8] {
+        &self.0.0
+    }
+
+    /// Returns the mapped memory mutably.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut [u8] {
+        &mut self.0.0
+    }
+
+    /// Flushes outstanding changes to the file and unmaps the memory. The memory is unmapped
+    /// even when flushing fails.
+    ///
+    /// # Errors
+    ///
+    /// When flushing fails, changes may not have reached the file then.
+    pub fn flush_and_unmap(self) -> io::Result<()> {
+        self.0.into().flush()
+    }
+
+    /// Returns the memory map, which is unmapped on drop without flushing again.
+    pub fn into_inner(self) -> MmapMut {
+        self.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearMmapMut;
+    use memmap2::MmapMut;
+
+    #[test]
+    fn anonymous() {
+        let mut map = LinearMmapMut::new(MmapMut::map_anon(4).unwrap());
+        map.get_mut()[0] = 1;
+        assert_eq!(map.get(), [1, 0, 0, 0]);
+        map.flush_and_unmap().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearMmapMut::new(MmapMut::map_anon(1).unwrap());
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{__linear_from_parts, MustUse};
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::sync::Arc;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Stream sockets a `LinearSocket` can wrap, `TcpStream` and, on unix, `UnixStream`.
+pub trait Socket: sealed::Sealed + Sized {
+    /// Reads from the socket through a shared reference.
+    ///
+    /// # Errors
+    ///
+    /// Like `Read::read()`.
+    fn read_shared(&self, buf: &mut [u8]) -> io::Result<usize>;
+    /// Writes to the socket through a shared reference.
+    ///
+    /// # Errors
+    ///
+    /// Like `Write::write()`.
+    fn write_shared(&self, buf: &[u8]) -> io::Result<usize>;
+    /// Flushes the socket through a shared reference.
+    ///
+    /// # Errors
+    ///
+    /// Like `Write::flush()`.
+    fn flush_shared(&self) -> io::Result<()>;
+    /// Shuts down the given directions of the socket.
+    ///
+    /// # Errors
+    ///
+    /// When the socket is not connected.
+    fn shutdown(&self, how: Shutdown) -> io::Result<()>;
+    /// Closes the socket.
+    ///
+    /// # Errors
+    ///
+    /// When closing fails.
+    fn close(self) -> io::Result<()>;
+}
+
+macro_rules! impl_socket {
+    ($ty:ty) => {
+        impl sealed::Sealed for $ty {}
+
+        impl Socket for $ty {
+            fn read_shared(&self, buf: &mut [u8]) -> io::Result<usize> {
+                Read::read(&mut &*self, buf)
+            }
+
+            fn write_shared(&self, buf: &[u8]) -> io::Result<usize> {
+                Write::write(&mut &*self, buf)
+            }
+
+            fn flush_shared(&self) -> io::Result<()> {
+                Write::flush(&mut &*self)
+            }
+
+            fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+                <$ty>::shutdown(self, how)
+            }
+
+            fn close(self) -> io::Result<()> {
+                crate::io::close(self)
+            }
+        }
+    };
+}
+
+impl_socket!(TcpStream);
+#[cfg(unix)]
+impl_socket!(UnixStream);
+
+/// A connected socket which must be explicitly shut down or closed, both return the errors
+/// `Drop` swallows. `split()` divides it into linear read and write halves.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::net::LinearTcpStream;
+/// use std::io::{Read, Write};
+/// use std::net::TcpListener;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0")?;
+/// let mut client = LinearTcpStream::connect(listener.local_addr()?)?;
+/// let mut server = LinearTcpStream::new(listener.accept()?.0);
+/// client.write_all(b"ping")?;
+/// client.shutdown()?;
+/// let mut ping = String::new();
+/// server.read_to_string(&mut ping)?;
+/// server.close()?;
+/// assert_eq!(ping, "ping");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearSocket<S>(MustUse<S>);
+
+/// Linear TCP stream.
+pub type LinearTcpStream = LinearSocket<TcpStream>;
+
+/// Linear unix domain stream.
+#[cfg(unix)]
+pub type LinearUnixStream = LinearSocket<UnixStream>;
+
+/// Linear read half of a split `LinearSocket`, it must be rejoined or shut down.
+#[must_use]
+pub struct ReadHalf<S>(MustUse<Arc<S>>);
+
+/// Linear write half of a split `LinearSocket`, it must be rejoined or shut down.
+#[must_use]
+pub struct WriteHalf<S>(MustUse<Arc<S>>);
+
+impl LinearTcpStream {
+    /// Opens a TCP connection, like `TcpStream::connect()`.
+    ///
+    /// # Errors
+    ///
+    /// When connecting fails.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        TcpStream::connect(addr).map(Self::new)
+    }
+}
+
+#[cfg(unix)]
+impl LinearUnixStream {
+    /// Connects to the socket at `path`, like `UnixStream::connect()`.
+    ///
+    /// # Errors
+    ///
+    /// When connecting fails.
+    pub fn connect(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        UnixStream::connect(path).map(Self::new)
+    }
+
+    /// Creates a pair of connected sockets, like `UnixStream::pair()`.
+    ///
+    /// # Errors
+    ///
+    /// When the sockets can not be created.
+    pub fn pair() -> io::Result<(Self, Self)> {
+        UnixStream::pair().map(|(a, b)| (Self::new(a), Self::new(b)))
+    }
+}
+
+impl<S: Socket> LinearSocket<S> {
+    /// Wraps a connected socket.
+    pub const fn new(socket: S) -> Self {
+        Self(__linear_from_parts(socket))
+    }
+
+    /// Returns a reference to the underlying socket.
+    #[must_use]
+    pub fn get(&self) -> &S {
+        &self.0.0
+    }
+
+    /// Shuts down both directions and closes the socket. The socket is closed even when
+    /// shutting down fails.
+    ///
+    /// # Errors
+    ///
+    /// When shutting down fails, e.g. the peer is gone already, otherwise like `close()`.
+    pub fn shutdown(self) -> io::Result<()> {
+        let socket = self.0.into();
+        let shut = socket.shutdown(Shutdown::Both);
+        shut.and(socket.close())
+    }
+
+    /// Closes the socket without shutting it down.
+    ///
+    /// # Errors
+    ///
+    /// When closing fails. Only reported on unix, elsewhere the socket is dropped.
+    pub fn close(self) -> io::Result<()> {
+        self.0.into().close()
+    }
+
+    /// Returns the underlying socket, which is closed on drop again.
+    pub fn into_std(self) -> S {
+        self.0.into()
+    }
+
+    /// Splits the socket into a read and a write half, each of them linear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::net::LinearUnixStream;
+    /// use std::io::{Read, Write};
+    ///
+    /// let (a, b) = LinearUnixStream::pair()?;
+    /// let (mut reader, mut writer) = a.split();
+    /// writer.write_all(b"half")?;
+    /// writer.shutdown()?;
+    /// let mut b = b;
+    /// let mut half = String::new();
+    /// b.read_to_string(&mut half)?;
+    /// b.shutdown()?;
+    /// assert_eq!(reader.read(&mut [0])?, 0);
+    /// reader.shutdown()?;
+    /// assert_eq!(half, "half");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn split(self) -> (ReadHalf<S>, WriteHalf<S>) {
+        let socket = Arc::new(self.0.into());
+        (
+            ReadHalf(__linear_from_parts(Arc::clone(&socket))),
+            WriteHalf(__linear_from_parts(socket)),
+        )
+    }
+}
+
+impl<S: Socket> ReadHalf<S> {
+    /// Rejoins both halves into the socket they were split from.
+    ///
+    /// # Panics
+    ///
+    /// When the halves were split from different sockets.
+    pub fn join(self, write: WriteHalf<S>) -> LinearSocket<S> {
+        let (read, write) = (self.0.into(), write.0.into());
+        assert!(
+            Arc::ptr_eq(&read, &write),
+            "halves of different sockets joined"
+        );
+        drop(write);
+        LinearSocket::new(
+            Arc::into_inner(read).unwrap_or_else(|| unreachable!("both halves are consumed")),
+        )
+    }
+
+    /// Shuts down the read direction, the socket is closed once both halves are consumed.
+    ///
+    /// # Errors
+    ///
+    /// When shutting down or closing fails.
+    pub fn shutdown(self) -> io::Result<()> {
+        release(self.0.into(), Shutdown::Read)
+    }
+}
+
+impl<S: Socket> WriteHalf<S> {
+    /// Shuts down the write direction, the socket is closed once both halves are consumed.
+    ///
+    /// # Errors
+    ///
+    /// When shutting down or closing fails.
+    pub fn shutdown(self) -> io::Result<()> {
+        release(self.0.into(), Shutdown::Write)
+    }
+}
+
+fn release<S: Socket>(socket: Arc<S>, how: Shutdown) -> io::Result<()> {
+    let shut = socket.shutdown(how);
+    match Arc::into_inner(socket) {
+        Some(socket) => shut.and(socket.close()),
+        None => shut,
+    }
+}
+
+impl<S: Socket> Read for LinearSocket<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.get().read_shared(buf)
+    }
+}
+
+impl<S: Socket> Write for LinearSocket<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get().write_shared(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get().flush_shared()
+    }
+}
+
+impl<S: Socket> Read for ReadHalf<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.0.read_shared(buf)
+    }
+}
+
+impl<S: Socket> Write for WriteHalf<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.0.write_shared(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.0.flush_shared()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::LinearUnixStream;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn rejoined() {
+        let (a, mut b) = LinearUnixStream::pair().unwrap();
+        let (reader, mut writer) = a.split();
+        writer.write_all(b"x").unwrap();
+        let mut a = reader.join(writer);
+        let mut byte = [0];
+        b.read_exact(&mut byte).unwrap();
+        b.write_all(&byte).unwrap();
+        a.read_exact(&mut byte).unwrap();
+        assert_eq!(&byte, b"x");
+        a.shutdown().unwrap();
+        b.close().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "halves of different sockets joined")]
+    fn joined_mismatch() {
+        let (a, b) = LinearUnixStream::pair().unwrap();
+        let (reader, _a_writer) = a.split();
+        let (_b_reader, writer) = b.split();
+        let _ = reader.join(writer);
+    }
+}
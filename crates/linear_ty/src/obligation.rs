@@ -0,0 +1,281 @@
+use crate::{__linear_from_parts, Linear, UniqueType};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+/// A linear value carrying a description of the obligation it represents. Failing to
+/// `discharge()` it aborts with the description instead of the generic "linear type dropped"
+/// message, which tells which of many linear values in flight was dropped.
+///
+/// # Panics or Aborts
+///
+/// Like any linear type, dropping an obligation panics in tests and aborts otherwise. The
+/// description is printed before aborting.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let response = Obligation::new(200, "response must be sent");
+/// assert_eq!(response.description(), "response must be sent");
+/// assert_eq!(response.discharge(), 200);
+/// ```
+///
+/// A branded obligation created by `Obligation::pair()` has no `discharge()`, it is discharged
+/// by the matching [`Fulfillment`] only.
+#[must_use]
+pub struct Obligation<T, B = ()> {
+    value: ManuallyDrop<T>,
+    description: &'static str,
+    brand: PhantomData<B>,
+}
+
+/// The only way to discharge the branded obligation created along with it by
+/// `Obligation::pair()`. It is linear too, hand it to the party which promised to fulfill the
+/// obligation, which has to give it back exactly once.
+#[must_use]
+pub struct Fulfillment<B>(Linear<(), B>);
+
+impl<T> Obligation<T> {
+    /// Creates an obligation holding `value`, described by `description`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let commit = Obligation::new("tx", "transaction must be committed");
+    /// commit.discharge();
+    /// ```
+    pub const fn new(value: T, description: &'static str) -> Self {
+        Self {
+            value: ManuallyDrop::new(value),
+            description,
+            brand: PhantomData,
+        }
+    }
+
+    /// Discharges the obligation and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let obligation = Obligation::new(1, "must be used");
+    /// assert_eq!(obligation.discharge(), 1);
+    /// ```
+    pub fn discharge(self) -> T {
+        self.take()
+    }
+
+    /// Discharges the obligation by applying a closure to the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let response = Obligation::new("body", "response must be sent");
+    /// assert_eq!(response.discharge_with(str::len), 4);
+    /// ```
+    pub fn discharge_with<F: FnOnce(T) -> R, R>(self, f: F) -> R {
+        f(self.discharge())
+    }
+}
+
+impl<T, F: Fn()> Obligation<T, UniqueType<F>> {
+    /// Creates an obligation along with its [`Fulfillment`]. Both are branded with a unique
+    /// type, thus the obligation can only be discharged by feeding it this very fulfillment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// fn call_back<B>(fulfillment: Fulfillment<B>) -> Fulfillment<B> {
+    ///     // ... calling back
+    ///     fulfillment
+    /// }
+    ///
+    /// let (obligation, fulfillment) = Obligation::pair(7, "must call back", unique!());
+    /// let fulfillment = call_back(fulfillment);
+    /// assert_eq!(obligation.fulfill(fulfillment), 7);
+    /// ```
+    ///
+    /// A fulfillment of another pair does not match:
+    ///
+    /// ```compile_fail
+    /// # use linear_ty::*;
+    /// let (a, a_fulfillment) = Obligation::pair((), "a", unique!());
+    /// let (b, b_fulfillment) = Obligation::pair((), "b", unique!());
+    /// a.fulfill(b_fulfillment);
+    /// # b.fulfill(a_fulfillment);
+    /// ```
+    pub const fn pair(
+        value: T,
+        description: &'static str,
+        _: UniqueType<F>,
+    ) -> (Self, Fulfillment<UniqueType<F>>) {
+        (
+            Self {
+                value: ManuallyDrop::new(value),
+                description,
+                brand: PhantomData,
+            },
+            Fulfillment(__linear_from_parts(())),
+        )
+    }
+
+    /// Discharges the obligation with its matching fulfillment and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (obligation, fulfillment) = Obligation::pair("tx", "must be committed", unique!());
+    /// assert_eq!(obligation.fulfill(fulfillment), "tx");
+    /// ```
+    pub fn fulfill(self, fulfillment: Fulfillment<UniqueType<F>>) -> T {
+        fulfillment.0.destroy();
+        self.take()
+    }
+}
+
+impl<T, B> Obligation<T, B> {
+    /// Returns the description of the obligation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let obligation = Obligation::new((), "must be done");
+    /// assert_eq!(obligation.description(), "must be done");
+    /// # obligation.discharge();
+    /// ```
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Transforms the inner value, the obligation and its description stay in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let response = Obligation::new(200, "response must be sent").map(|x| x.to_string());
+    /// assert_eq!(response.discharge(), "200");
+    /// ```
+    pub fn map<F: FnOnce(T) -> R, R>(self, f: F) -> Obligation<R, B> {
+        let description = self.description;
+        Obligation {
+            value: ManuallyDrop::new(f(self.take())),
+            description,
+            brand: PhantomData,
+        }
+    }
+
+    fn take(self) -> T {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used or dropped again.
+        unsafe { ManuallyDrop::take(&mut this.value) }
+    }
+}
+
+/// A standalone drop bomb without payload. Dropping it without `defuse()` aborts with its
+/// reason, like an `Obligation<()>`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let bomb = DropBomb::new("connection must be closed");
+/// assert_eq!(bomb.reason(), "connection must be closed");
+/// bomb.defuse();
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct DropBomb(Obligation<()>);
+
+impl DropBomb {
+    /// Creates a bomb aborting with `reason` when dropped.
+    pub const fn new(reason: &'static str) -> Self {
+        Self(Obligation::new((), reason))
+    }
+
+    /// Returns the reason reported when the bomb is dropped.
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        self.0.description()
+    }
+
+    /// Defuses the bomb.
+    pub fn defuse(self) {
+        self.0.discharge();
+    }
+}
+
+impl<T: core::fmt::Debug, B> core::fmt::Debug for Obligation<T, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Obligation")
+            .field(&*self.value)
+            .field(&self.description)
+            .finish()
+    }
+}
+
+/// Drop is only implemented when either `debug_assertions` are enabled or the
+/// `drop_unchecked` feature is not enabled.
+#[cfg(any(debug_assertions, not(feature = "drop_unchecked")))]
+impl<T, B> Drop for Obligation<T, B> {
+    #[cfg(test)]
+    fn drop(&mut self) {
+        // Avoid double panic when we are already panicking
+        #[allow(clippy::manual_assert)]
+        if !std::thread::panicking() {
+            panic!("{}", self.description);
+        }
+    }
+    #[cfg(all(not(test), feature = "std"))]
+    fn drop(&mut self) {
+        eprintln!("{}", self.description);
+        std::process::abort();
+    }
+    #[cfg(not(any(test, feature = "std")))]
+    fn drop(&mut self) {
+        panic!("{}", self.description);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DropBomb, Obligation};
+    use crate::unique;
+
+    #[test]
+    #[should_panic(expected = "response must be sent")]
+    fn dropped() {
+        let _ = Obligation::new(200, "response must be sent");
+    }
+
+    #[test]
+    #[should_panic(expected = "mapped obligation")]
+    fn dropped_after_map() {
+        let _ = Obligation::new(1, "mapped obligation").map(|x| x + 1);
+    }
+
+    #[test]
+    fn fulfilled() {
+        let (obligation, fulfillment) = Obligation::pair(1, "must call back", unique!());
+        let obligation = obligation.map(|x| x + 1);
+        assert_eq!(obligation.fulfill(fulfillment), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "must call back")]
+    fn unfulfilled() {
+        let (_, fulfillment) = Obligation::pair((), "must call back", unique!());
+        fulfillment.0.destroy();
+    }
+
+    #[test]
+    #[should_panic(expected = "bomb not defused")]
+    fn bomb_dropped() {
+        let _ = DropBomb::new("bomb not defused");
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{__linear_from_parts, MustUse};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A cell initialized exactly once and taken exactly once. It is created along with a linear
+/// `Initializer` which must set the value and a linear `Taker` which must take it out again.
+/// Between both the value is shared by `get()`. The tokens are paired with their cell at
+/// runtime, a token of another cell is handed back.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let (mut config, initializer, taker) = LinearOnceCell::new();
+/// assert_eq!(config.get(), None);
+/// assert!(config.set(initializer, "debug").is_ok());
+/// assert_eq!(config.get(), Some(&"debug"));
+/// assert_eq!(config.take(taker).ok(), Some("debug"));
+/// ```
+pub struct LinearOnceCell<T> {
+    value: OnceLock<T>,
+    id: u64,
+}
+
+/// Linear token setting the value of the `LinearOnceCell` created along with it.
+#[must_use]
+pub struct Initializer(MustUse<u64>);
+
+/// Linear token taking the value out of the `LinearOnceCell` created along with it.
+#[must_use]
+pub struct Taker(MustUse<u64>);
+
+impl<T> LinearOnceCell<T> {
+    /// Creates an empty cell along with its `Initializer` and `Taker`.
+    pub fn new() -> (Self, Initializer, Taker) {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        (
+            Self {
+                value: OnceLock::new(),
+                id,
+            },
+            Initializer(__linear_from_parts(id)),
+            Taker(__linear_from_parts(id)),
+        )
+    }
+
+    /// Sets the value, consuming the only initializer of the cell.
+    ///
+    /// # Errors
+    ///
+    /// Hands back the initializer along with the value when the initializer belongs to another
+    /// cell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (mut a, a_initializer, a_taker) = LinearOnceCell::new();
+    /// let (mut b, b_initializer, b_taker) = LinearOnceCell::new();
+    /// let (b_initializer, value) = a.set(b_initializer, 1).err().unwrap();
+    /// assert!(b.set(b_initializer, value).is_ok());
+    /// # assert!(a.set(a_initializer, 2).is_ok());
+    /// # assert_eq!((a.take(a_taker).ok(), b.take(b_taker).ok()), (Some(2), Some(1)));
+    /// ```
+    pub fn set(&self, initializer: Initializer, value: T) -> Result<(), (Initializer, T)> {
+        if *initializer.0.0 != self.id {
+            return Err((initializer, value));
+        }
+        initializer.0.destroy();
+        self.value
+            .set(value)
+            .unwrap_or_else(|_| unreachable!("a cell has only one initializer"));
+        Ok(())
+    }
+
+    /// Returns the value once it is set.
+    #[must_use]
+    pub fn get(&self) -> Option<&T> {
+        self.value.get()
+    }
+
+    /// Takes the value out, or returns the taker back when the value is not set yet.
+    ///
+    /// # Errors
+    ///
+    /// When the value is not set yet or the taker belongs to another cell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (mut cell, initializer, taker) = LinearOnceCell::new();
+    /// let taker = cell.take(taker).err().unwrap();
+    /// assert!(cell.set(initializer, 1).is_ok());
+    /// assert_eq!(cell.take(taker).ok(), Some(1));
+    /// ```
+    pub fn take(&mut self, taker: Taker) -> Result<T, Taker> {
+        if *taker.0.0 != self.id {
+            return Err(taker);
+        }
+        match self.value.take() {
+            Some(value) => {
+                taker.0.destroy();
+                Ok(value)
+            }
+            None => Err(taker),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearOnceCell;
+
+    #[test]
+    fn shared_until_taken() {
+        let (mut cell, initializer, taker) = LinearOnceCell::new();
+        std::thread::scope(|s| {
+            s.spawn(|| assert!(cell.set(initializer, vec![1, 2]).is_ok()));
+        });
+        std::thread::scope(|s| {
+            s.spawn(|| assert_eq!(cell.get().map(Vec::len), Some(2)));
+        });
+        assert_eq!(cell.take(taker).ok(), Some(vec![1, 2]));
+        assert_eq!(cell.get(), None);
+    }
+
+    #[test]
+    fn tokens_of_another_cell() {
+        let mut cells: Vec<_> = (0..2).map(|_| LinearOnceCell::new()).collect();
+        let (mut b, b_initializer, b_taker) = cells.pop().unwrap();
+        let (mut a, a_initializer, a_taker) = cells.pop().unwrap();
+        let (b_initializer, value) = a.set(b_initializer, 1).err().unwrap();
+        assert!(b.set(b_initializer, value).is_ok());
+        assert!(a.set(a_initializer, 2).is_ok());
+        let b_taker = a.take(b_taker).err().unwrap();
+        assert_eq!(
+            (a.take(a_taker).ok(), b.take(b_taker).ok()),
+            (Some(2), Some(1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn taker_dropped() {
+        let (cell, initializer, _) = LinearOnceCell::new();
+        assert!(cell.set(initializer, ()).is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{__linear_from_parts, Linear};
+use core::pin::Pin;
+
+/// Additional `into_pin()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Moves the inner value into a pinned box. The result is still linear and must be
+    /// consumed, a pinned future can be driven by `poll()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let pinned = new_linear!(123).into_pin();
+    /// assert_eq!(*pinned.into(), 123);
+    /// ```
+    pub fn into_pin(self) -> Linear<Pin<Box<T>>, Self> {
+        __linear_from_parts(Box::pin(self.into()))
+    }
+}
+
+/// Pinned projections for `Linear<Pin<Box<T>>>`.
+impl<T: ?Sized, U> Linear<Pin<Box<T>>, U> {
+    /// Polls the pinned future while the value stays linear, thus a manually polled future
+    /// can be driven without giving up its linearity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::task::{Context, Poll, Waker};
+    /// let mut future = new_linear!(async { 42 }).into_pin();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(future.poll(&mut cx), Poll::Ready(42));
+    /// future.destroy();
+    /// ```
+    pub fn poll(&mut self, cx: &mut core::task::Context<'_>) -> core::task::Poll<T::Output>
+    where
+        T: Future,
+    {
+        self.0.as_mut().poll(cx)
+    }
+
+    #[cfg(any(doc, feature = "semipure_mut"))]
+    /// Returns a pinned mutable reference to the inner value. A value that is not `Unpin` can
+    /// not be moved out or replaced through the returned reference.
+    ///
+    /// # Safety
+    ///
+    /// Same as `get_mut()`, an `Unpin` inner value is freely mutable through the returned
+    /// reference. It must not be replaced by a value made up from thin air.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut pinned = new_linear!(vec![1]).into_pin();
+    /// # #[cfg(feature = "semipure_mut")]
+    /// unsafe { pinned.as_pin_mut() }.push(2);
+    /// # #[cfg(feature = "semipure_mut")]
+    /// assert_eq!(*pinned.into(), [1, 2]);
+    /// # #[cfg(not(feature = "semipure_mut"))]
+    /// # pinned.destroy();
+    /// ```
+    pub unsafe fn as_pin_mut(&mut self) -> Pin<&mut T> {
+        self.0.as_mut()
+    }
+
+    #[cfg(any(doc, feature = "semipure"))]
+    /// Returns a pinned shared reference to the inner value.
+    ///
+    /// # Safety
+    ///
+    /// Same as `get_ref()`, the inner value must not be cloned or mutated through interior
+    /// mutability.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let pinned = new_linear!(123).into_pin();
+    /// # #[cfg(feature = "semipure")]
+    /// assert_eq!(*unsafe { pinned.as_pin_ref() }, 123);
+    /// pinned.destroy();
+    /// ```
+    pub unsafe fn as_pin_ref(&self) -> Pin<&T> {
+        self.0.as_ref()
+    }
+}
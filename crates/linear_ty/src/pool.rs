@@ -0,0 +1,176 @@
+use crate::{__linear_from_parts, MustUse};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A pool of items lent out as linear `Lease`s. A lease must be given back with
+/// `Pool::release()` or explicitly forfeited with a reason, a pool never silently loses its
+/// items.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let pool = Pool::new(["connection a", "connection b"]);
+/// let lease = pool.acquire().unwrap();
+/// assert_eq!(*lease.get(), "connection b");
+/// assert_eq!(pool.available(), 1);
+/// assert!(pool.release(lease).is_ok());
+/// assert_eq!(pool.available(), 2);
+/// ```
+pub struct Pool<T> {
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    idle: Vec<T>,
+    leased: usize,
+    forfeits: Vec<&'static str>,
+}
+
+/// An item lent by a `Pool`, it can only be consumed by `Pool::release()` or
+/// `Lease::forfeit()`.
+#[must_use]
+#[cfg_attr(
+    feature = "nightly",
+    must_not_suspend = "release the lease before the `.await`"
+)]
+pub struct Lease<'p, T>(MustUse<(T, &'p Pool<T>)>);
+
+impl<T> Pool<T> {
+    /// Creates a pool of the given items.
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            state: Mutex::new(State {
+                idle: items.into_iter().collect(),
+                leased: 0,
+                forfeits: Vec::new(),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State<T>> {
+        // The state is consistent between statements, a poisoned lock holds a valid state.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Leases an idle item, `None` when all items are leased.
+    pub fn acquire(&self) -> Option<Lease<'_, T>> {
+        let mut state = self.state();
+        let item = state.idle.pop()?;
+        state.leased += 1;
+        Some(Lease(__linear_from_parts((item, self))))
+    }
+
+    /// Takes a leased item back.
+    ///
+    /// # Errors
+    ///
+    /// Gives the lease back when it was acquired from another pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let (a, b) = (Pool::new([1]), Pool::new([2]));
+    /// let lease = b.release(a.acquire().unwrap()).unwrap_err();
+    /// assert!(a.release(lease).is_ok());
+    /// ```
+    pub fn release<'p>(&self, lease: Lease<'p, T>) -> Result<(), Lease<'p, T>> {
+        if !core::ptr::eq(lease.0.0.1, self) {
+            return Err(lease);
+        }
+        let (item, _) = lease.0.into();
+        let mut state = self.state();
+        state.leased -= 1;
+        state.idle.push(item);
+        Ok(())
+    }
+
+    /// Returns the number of idle items.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.state().idle.len()
+    }
+
+    /// Returns the number of leased items.
+    #[must_use]
+    pub fn leased(&self) -> usize {
+        self.state().leased
+    }
+
+    /// Returns the reasons of all forfeited leases.
+    #[must_use]
+    pub fn forfeits(&self) -> Vec<&'static str> {
+        self.state().forfeits.clone()
+    }
+}
+
+impl<T> Lease<'_, T> {
+    /// Returns a reference to the leased item.
+    pub fn get(&self) -> &T {
+        &self.0.0.0
+    }
+
+    /// Returns a mutable reference to the leased item.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0.0.0
+    }
+
+    /// Gives up the lease without returning the item to the pool, e.g. a broken connection. The
+    /// pool records the reason and the item is returned to the caller to dispose of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let pool = Pool::new([1]);
+    /// let broken = pool.acquire().unwrap().forfeit("connection reset");
+    /// assert_eq!(broken, 1);
+    /// assert_eq!(pool.forfeits(), ["connection reset"]);
+    /// assert!(pool.acquire().is_none());
+    /// ```
+    pub fn forfeit(self, reason: &'static str) -> T {
+        let (item, pool) = self.0.into();
+        let mut state = pool.state();
+        state.leased -= 1;
+        state.forfeits.push(reason);
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pool;
+
+    #[test]
+    fn leased_and_released() {
+        let pool = Pool::new(vec![String::from("a")]);
+        let mut lease = pool.acquire().unwrap();
+        assert!(pool.acquire().is_none());
+        lease.get_mut().push('b');
+        assert_eq!(pool.leased(), 1);
+        assert!(pool.release(lease).is_ok());
+        let lease = pool.acquire().unwrap();
+        assert_eq!(lease.get(), "ab");
+        assert_eq!(lease.forfeit("done"), "ab");
+        assert_eq!((pool.available(), pool.leased()), (0, 0));
+    }
+
+    #[test]
+    fn other_pool() {
+        let a = Pool::new([1]);
+        let b = Pool::new([2]);
+        let Err(lease) = b.release(a.acquire().unwrap()) else {
+            panic!("lease released to another pool");
+        };
+        assert_eq!((a.leased(), b.leased()), (1, 0));
+        assert!(a.release(lease).is_ok());
+        assert_eq!((a.available(), b.available()), (1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let pool = Pool::new([1]);
+        let _ = pool.acquire();
+    }
+}
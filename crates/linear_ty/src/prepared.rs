@@ -0,0 +1,123 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// The second phase of a two-phase commit, implemented by the state a preparation step leaves
+/// behind.
+pub trait TwoPhase {
+    /// Result of committing.
+    type Committed;
+    /// Result of aborting.
+    type Aborted;
+
+    /// Makes the prepared change effective.
+    fn commit(self) -> Self::Committed;
+
+    /// Reverts the prepared change.
+    fn abort(self) -> Self::Aborted;
+}
+
+/// A prepared change returned by a preparation step, it must be either committed or aborted.
+/// Nothing else can be done with it.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// struct Reload<'a> {
+///     config: &'a mut String,
+///     next: String,
+/// }
+///
+/// impl TwoPhase for Reload<'_> {
+///     type Committed = ();
+///     type Aborted = String;
+///
+///     fn commit(self) {
+///         *self.config = self.next;
+///     }
+///
+///     fn abort(self) -> String {
+///         self.next
+///     }
+/// }
+///
+/// fn prepare<'a>(config: &'a mut String, next: &str) -> Prepared<Reload<'a>> {
+///     Prepared::new(Reload { config, next: next.to_string() })
+/// }
+///
+/// let mut config = String::from("v1");
+/// prepare(&mut config, "v2").commit();
+/// assert_eq!(prepare(&mut config, "v3").abort(), "v3");
+/// assert_eq!(config, "v2");
+/// ```
+#[must_use]
+pub struct Prepared<T: TwoPhase>(MustUse<T>);
+
+impl<T: TwoPhase> Prepared<T> {
+    /// Wraps the state left behind by a preparation step.
+    pub const fn new(prepared: T) -> Self {
+        Self(__linear_from_parts(prepared))
+    }
+
+    /// Commits the prepared change.
+    pub fn commit(self) -> T::Committed {
+        self.0.into().commit()
+    }
+
+    /// Aborts the prepared change.
+    pub fn abort(self) -> T::Aborted {
+        self.0.into().abort()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prepared, TwoPhase};
+
+    struct Rename<'a> {
+        files: &'a mut Vec<&'static str>,
+        from: &'static str,
+        to: &'static str,
+    }
+
+    impl TwoPhase for Rename<'_> {
+        type Committed = usize;
+        type Aborted = ();
+
+        fn commit(self) -> usize {
+            let index = self.files.iter().position(|f| *f == self.from).unwrap();
+            self.files[index] = self.to;
+            index
+        }
+
+        fn abort(self) {}
+    }
+
+    #[test]
+    fn committed_or_aborted() {
+        let mut files = vec!["a", "b"];
+        Prepared::new(Rename {
+            files: &mut files,
+            from: "a",
+            to: "c",
+        })
+        .abort();
+        let index = Prepared::new(Rename {
+            files: &mut files,
+            from: "b",
+            to: "d",
+        })
+        .commit();
+        assert_eq!((index, files), (1, vec!["a", "d"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let mut files = vec![];
+        let _ = Prepared::new(Rename {
+            files: &mut files,
+            from: "a",
+            to: "b",
+        });
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{__linear_from_parts, MustUse};
+use std::io;
+use std::process::{Child, Command, ExitStatus, Output};
+
+/// A child process which must be reaped. Dropping a `std::process::Child` neither waits for
+/// nor kills the process, which leaves a zombie behind. The only ways to consume a
+/// `LinearChild` are `wait()`, `wait_with_output()` and `kill_and_reap()`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::process::LinearChild;
+/// use std::process::Command;
+///
+/// # #[cfg(unix)] {
+/// let child = LinearChild::spawn(&mut Command::new("true"))?;
+/// assert!(child.wait()?.success());
+///
+/// let sleeper = LinearChild::spawn(Command::new("sleep").arg("60"))?;
+/// assert!(!sleeper.kill_and_reap()?.success());
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct LinearChild(MustUse<Child>);
+
+impl LinearChild {
+    /// Wraps a spawned child process.
+    pub const fn new(child: Child) -> Self {
+        Self(__linear_from_parts(child))
+    }
+
+    /// Spawns a command as child process, like `Command::spawn()`.
+    ///
+    /// # Errors
+    ///
+    /// When the process can not be spawned.
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        command.spawn().map(Self::new)
+    }
+
+    /// Returns the OS assigned process identifier.
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.0.0.id()
+    }
+
+    /// Returns a mutable reference to the child, e.g. to take its stdio handles.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut Child {
+        &mut self.0.0
+    }
+
+    /// Returns the exit status when the child exited already, without reaping it. The child
+    /// must still be consumed, `wait()` returns immediately then.
+    ///
+    /// # Errors
+    ///
+    /// Like `Child::try_wait()`.
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.0.0.try_wait()
+    }
+
+    /// Waits for the child to exit and reaps it.
+    ///
+    /// # Errors
+    ///
+    /// Like `Child::wait()`.
+    pub fn wait(self) -> io::Result<ExitStatus> {
+        self.0.into().wait()
+    }
+
+    /// Waits for the child to exit collecting its piped stdout and stderr, and reaps it.
+    ///
+    /// # Errors
+    ///
+    /// Like `Child::wait_with_output()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::process::LinearChild;
+    /// use std::process::{Command, Stdio};
+    ///
+    /// # #[cfg(unix)] {
+    /// let child = LinearChild::spawn(Command::new("echo").arg("hi").stdout(Stdio::piped()))?;
+    /// assert_eq!(child.wait_with_output()?.stdout, b"hi\n");
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn wait_with_output(self) -> io::Result<Output> {
+        self.0.into().wait_with_output()
+    }
+
+    /// Kills the child and reaps it. A child which exited already is just reaped.
+    ///
+    /// # Errors
+    ///
+    /// When killing or waiting fails.
+    pub fn kill_and_reap(self) -> io::Result<ExitStatus> {
+        let mut child = self.0.into();
+        let killed = child.kill();
+        let status = child.wait()?;
+        killed.map(|()| status)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::LinearChild;
+    use std::process::Command;
+
+    #[test]
+    fn reap_exited() {
+        let mut child = LinearChild::spawn(&mut Command::new("true")).unwrap();
+        while child.try_wait().unwrap().is_none() {
+            std::thread::yield_now();
+        }
+        assert!(child.kill_and_reap().unwrap().success());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = LinearChild::spawn(&mut Command::new("true")).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Linear;
+use core::marker::PhantomData;
+
+/// A property checked on a value. The check consumes the value, its output is returned bound
+/// to the witness of the property on success.
+pub trait Property<T> {
+    /// Value passed on once the property holds.
+    type Output;
+    /// Error returned when the property does not hold.
+    type Error;
+
+    /// Checks the property.
+    ///
+    /// # Errors
+    ///
+    /// When the property does not hold.
+    fn check(value: T) -> Result<Self::Output, Self::Error>;
+}
+
+/// A value along with the witness that the property `P` holds for it, only obtainable from
+/// `Linear::prove()`. It can not be cloned, APIs demand it as a parameter to encode "only after
+/// the check succeeded" in their signatures and get exactly the checked value.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// struct Validated;
+///
+/// impl Property<String> for Validated {
+///     type Output = String;
+///     type Error = &'static str;
+///
+///     fn check(message: String) -> Result<String, &'static str> {
+///         if message.is_empty() { Err("empty message") } else { Ok(message) }
+///     }
+/// }
+///
+/// fn send(message: Proven<Validated, String>) -> usize {
+///     message.into_inner().len()
+/// }
+///
+/// let message = new_linear!(String::from("hello")).prove::<Validated>().unwrap();
+/// assert_eq!(send(message), 5);
+/// assert!(new_linear!(String::new()).prove::<Validated>().is_err());
+/// ```
+pub struct Proven<P, T>(T, PhantomData<fn() -> P>);
+
+impl<P, T> Proven<P, T> {
+    /// Returns a reference to the checked value.
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the checked value, giving up the witness.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<P, T: core::fmt::Debug> core::fmt::Debug for Proven<P, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Proven")
+            .field(&core::any::type_name::<P>())
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/// Additional `prove()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Consumes the value by checking the property `P` and returns the output of the check
+    /// bound to the witness of the property.
+    ///
+    /// # Errors
+    ///
+    /// When the property does not hold.
+    pub fn prove<P: Property<T>>(self) -> Result<Proven<P, P::Output>, P::Error> {
+        P::check(self.into()).map(|output| Proven(output, PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Property, Proven};
+    use static_assertions::assert_not_impl_any;
+
+    struct Even;
+
+    impl Property<u32> for Even {
+        type Output = u32;
+        type Error = u32;
+
+        fn check(value: u32) -> Result<u32, u32> {
+            if value % 2 == 0 {
+                Ok(value)
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    assert_not_impl_any!(Proven<Even, u32>: Clone, Copy);
+
+    fn half(value: Proven<Even, u32>) -> u32 {
+        value.into_inner() / 2
+    }
+
+    #[test]
+    fn proved() {
+        let value = crate::new_linear!(4).prove::<Even>().unwrap();
+        assert_eq!(core::mem::size_of_val(&value), 4);
+        assert_eq!(*value.get(), 4);
+        assert_eq!(half(value), 2);
+        assert_eq!(crate::new_linear!(3).prove::<Even>().err(), Some(3));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{__linear_from_parts, __linearity, Linear};
+use ::rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+
+/// Additional parallel consuming methods for `Linear<Vec<T>>`, available with the `rayon`
+/// feature.
+impl<T: Send, U> Linear<Vec<T>, U> {
+    /// Consumes each element with a closure on the rayon thread pool and collects the results
+    /// into a new linear vector, in order. Each element is consumed exactly once.
+    ///
+    /// # Panics or Aborts
+    ///
+    /// A panicking closure leaves its element unconsumed, which is a linearity violation
+    /// reported like dropping a linear value, once all other elements are consumed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let squares = new_linear!(vec![1, 2, 3]).par_map_consume(|x| x * x);
+    /// assert_eq!(squares.into(), vec![1, 4, 9]);
+    /// ```
+    pub fn par_map_consume<F, R>(self, f: F) -> Linear<Vec<R>, Self>
+    where
+        F: Fn(T) -> R + Sync + Send,
+        R: Send,
+    {
+        let results: Vec<_> = self
+            .into()
+            .into_par_iter()
+            .map(|item| catch_unwind(AssertUnwindSafe(|| f(item))))
+            .collect();
+        __linear_from_parts(
+            results
+                .into_iter()
+                .map(|result| result.unwrap_or_else(|payload| violated(payload)))
+                .collect(),
+        )
+    }
+
+    /// Consumes each element with a closure on the rayon thread pool, this terminates the
+    /// linear vector.
+    ///
+    /// # Panics or Aborts
+    ///
+    /// Like `par_map_consume()` when the closure panics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let sum = AtomicU32::new(0);
+    /// new_linear!(vec![1, 2, 3]).par_for_each_consume(|x| {
+    ///     sum.fetch_add(x, Ordering::Relaxed);
+    /// });
+    /// assert_eq!(sum.into_inner(), 6);
+    /// ```
+    pub fn par_for_each_consume<F: Fn(T) + Sync + Send>(self, f: F) {
+        let panic = self
+            .into()
+            .into_par_iter()
+            .filter_map(|item| catch_unwind(AssertUnwindSafe(|| f(item))).err())
+            .find_any(|_| true);
+        if let Some(payload) = panic {
+            violated(payload);
+        }
+    }
+}
+
+/// An element was not consumed because its worker panicked. Takes the same path as dropping a
+/// linear value, then carries on with the original panic when that path returns.
+fn violated(payload: Box<dyn std::any::Any + Send>) -> ! {
+    drop(__linearity::<()>());
+    resume_unwind(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::new_linear;
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn worker_panicked() {
+        let _ = new_linear!(vec![1, 2, 0]).par_map_consume(|x| 6 / x);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn for_each_worker_panicked() {
+        new_linear!(vec![1, 2, 3]).par_for_each_consume(|x| assert_ne!(x, 2));
+    }
+}
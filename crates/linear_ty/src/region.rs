@@ -0,0 +1,131 @@
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+/// Invariant lifetime brand, ties tokens to the region they were minted in.
+type Brand<'r> = PhantomData<fn(&'r ()) -> &'r ()>;
+
+/// A region counting the tokens minted in it. The brand lifetime keeps tokens from escaping
+/// the region closure and all of them must be consumed before the closure returns. This
+/// detects leaks of a whole batch of obligations at once.
+///
+/// # Panics or Aborts
+///
+/// Like a dropped linear value, when tokens are left unconsumed at the end of the region.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let sum = Region::enter(|region| {
+///     let tokens: Vec<_> = (1..=3).map(|x| region.mint(x)).collect();
+///     assert_eq!(region.outstanding(), 3);
+///     tokens.into_iter().map(RegionToken::into).sum::<i32>()
+/// });
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// Tokens can not escape the region:
+///
+/// ```compile_fail
+/// # use linear_ty::*;
+/// let token = Region::enter(|region| region.mint(1));
+/// ```
+pub struct Region<'r> {
+    outstanding: Cell<usize>,
+    brand: Brand<'r>,
+}
+
+/// A token minted in a `Region`, it must be consumed before the region exits.
+#[must_use]
+pub struct RegionToken<'r, T> {
+    value: T,
+    region: &'r Region<'r>,
+}
+
+impl Region<'_> {
+    /// Runs a closure within a new region and checks that all tokens minted in it were
+    /// consumed.
+    pub fn enter<F, R>(f: F) -> R
+    where
+        F: for<'r> FnOnce(&'r Region<'r>) -> R,
+    {
+        let region = Region {
+            outstanding: Cell::new(0),
+            brand: PhantomData,
+        };
+        let result = f(&region);
+        let outstanding = region.outstanding.get();
+        if outstanding != 0 {
+            unconsumed(outstanding);
+        }
+        result
+    }
+}
+
+impl<'r> Region<'r> {
+    /// Mints a token holding `value`.
+    pub fn mint<T>(&'r self, value: T) -> RegionToken<'r, T> {
+        self.outstanding.set(self.outstanding.get() + 1);
+        RegionToken {
+            value,
+            region: self,
+        }
+    }
+
+    /// Returns the number of minted tokens not consumed yet.
+    #[must_use]
+    pub fn outstanding(&self) -> usize {
+        self.outstanding.get()
+    }
+}
+
+impl<T> RegionToken<'_, T> {
+    /// Consumes the token and returns the inner value.
+    pub fn into(self) -> T {
+        let outstanding = &self.region.outstanding;
+        outstanding.set(outstanding.get() - 1);
+        self.value
+    }
+}
+
+#[cfg(test)]
+fn unconsumed(outstanding: usize) {
+    panic!("region exited with {outstanding} unconsumed tokens");
+}
+
+#[cfg(all(not(test), feature = "std"))]
+fn unconsumed(outstanding: usize) {
+    eprintln!("region exited with {outstanding} unconsumed tokens");
+    std::process::abort();
+}
+
+#[cfg(not(any(test, feature = "std")))]
+fn unconsumed(outstanding: usize) {
+    panic!("region exited with {outstanding} unconsumed tokens");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Region;
+
+    #[test]
+    fn nested() {
+        let result = Region::enter(|outer| {
+            let token = outer.mint("outer");
+            let inner = Region::enter(|inner| inner.mint(1).into());
+            assert_eq!(outer.outstanding(), 1);
+            (token.into(), inner)
+        });
+        assert_eq!(result, ("outer", 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "region exited with 2 unconsumed tokens")]
+    fn unconsumed() {
+        Region::enter(|region| {
+            let _ = region.mint(1);
+            let _ = region.mint(2);
+            region.mint(3).into()
+        });
+    }
+}
@@ -0,0 +1,97 @@
+use crate::DropBomb;
+use rusqlite::{Connection, Transaction};
+
+/// A rusqlite transaction whose only exits are `commit()` and `rollback()`, both returning the
+/// error of the driver. Dropping it aborts with "transaction neither committed nor rolled
+/// back" instead of rolling back silently.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::rusqlite::LinearRusqliteTransaction;
+///
+/// let mut conn = rusqlite::Connection::open_in_memory()?;
+/// conn.execute("CREATE TABLE kv (k TEXT, v TEXT)", ())?;
+/// let tx = LinearRusqliteTransaction::begin(&mut conn)?;
+/// tx.get().execute("INSERT INTO kv VALUES ('a', 'b')", ())?;
+/// tx.commit()?;
+/// let count: u32 = conn.query_row("SELECT COUNT(*) FROM kv", (), |row| row.get(0))?;
+/// assert_eq!(count, 1);
+/// # Ok::<(), rusqlite::Error>(())
+/// ```
+#[must_use]
+pub struct LinearRusqliteTransaction<'c> {
+    tx: Transaction<'c>,
+    bomb: DropBomb,
+}
+
+impl<'c> LinearRusqliteTransaction<'c> {
+    /// Wraps a transaction.
+    pub const fn new(tx: Transaction<'c>) -> Self {
+        Self {
+            tx,
+            bomb: DropBomb::new("transaction neither committed nor rolled back"),
+        }
+    }
+
+    /// Begins a deferred transaction, like `Connection::transaction()`.
+    ///
+    /// # Errors
+    ///
+    /// When the transaction can not be started.
+    pub fn begin(conn: &'c mut Connection) -> rusqlite::Result<Self> {
+        conn.transaction().map(Self::new)
+    }
+
+    /// Returns the transaction, to run its statements.
+    #[must_use]
+    pub const fn get(&self) -> &Transaction<'c> {
+        &self.tx
+    }
+
+    /// Commits the transaction.
+    ///
+    /// # Errors
+    ///
+    /// When committing fails.
+    pub fn commit(self) -> rusqlite::Result<()> {
+        self.bomb.defuse();
+        self.tx.commit()
+    }
+
+    /// Rolls the transaction back.
+    ///
+    /// # Errors
+    ///
+    /// When rolling back fails.
+    pub fn rollback(self) -> rusqlite::Result<()> {
+        self.bomb.defuse();
+        self.tx.rollback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearRusqliteTransaction;
+    use rusqlite::Connection;
+
+    #[test]
+    fn rolled_back() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE t (x INTEGER)", ()).unwrap();
+        let tx = LinearRusqliteTransaction::begin(&mut conn).unwrap();
+        tx.get().execute("INSERT INTO t VALUES (1)", ()).unwrap();
+        tx.rollback().unwrap();
+        let count: u32 = conn
+            .query_row("SELECT COUNT(*) FROM t", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction neither committed nor rolled back")]
+    fn dropped() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let _ = LinearRusqliteTransaction::begin(&mut conn).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use crate::Linear;
+use core::marker::PhantomData;
+
+/// Invariant lifetime brand, ties `Scoped` values and `Consumed` proofs to one scope.
+type Brand<'s> = PhantomData<fn(&'s ()) -> &'s ()>;
+
+/// A linear value passed into `Linear::scope()`. Consuming it is the only way to obtain the
+/// `Consumed` proof the scope closure has to return.
+#[must_use]
+pub struct Scoped<'s, T, U> {
+    linear: Linear<T, U>,
+    brand: Brand<'s>,
+}
+
+/// Proof that the `Scoped` value of a scope was consumed. Carries the result of the
+/// consumption.
+#[must_use]
+pub struct Consumed<'s, R> {
+    value: R,
+    brand: Brand<'s>,
+}
+
+/// Additional `scope()` method for any `Linear<T>`.
+impl<T, U> Linear<T, U> {
+    /// Runs a closure with the linear value wrapped in a `Scoped`. The closure must return the
+    /// `Consumed` proof which can only be obtained by consuming the `Scoped` value. Thus it is
+    /// guaranteed at compile time that the value is consumed before the scope exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let number = new_linear!(21);
+    /// let doubled = Linear::scope(number, |scoped| {
+    ///     let scoped = scoped.map(|x| x * 2);
+    ///     scoped.into()
+    /// });
+    /// assert_eq!(doubled, 42);
+    /// ```
+    ///
+    /// Returning without consuming the value does not compile:
+    ///
+    /// ```compile_fail
+    /// # use linear_ty::*;
+    /// let number = new_linear!(21);
+    /// let answer = number.scope(|scoped| 42);
+    /// ```
+    pub fn scope<F, R>(self, f: F) -> R
+    where
+        F: for<'s> FnOnce(Scoped<'s, T, U>) -> Consumed<'s, R>,
+    {
+        f(Scoped {
+            linear: self,
+            brand: PhantomData,
+        })
+        .value
+    }
+}
+
+impl<'s, T, U> Scoped<'s, T, U> {
+    /// Destructures the scoped value and returns the inner value within the proof.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let text = new_linear!("text").scope(|scoped| scoped.into());
+    /// assert_eq!(text, "text");
+    /// ```
+    pub fn into(self) -> Consumed<'s, T> {
+        Consumed {
+            value: self.linear.into(),
+            brand: PhantomData,
+        }
+    }
+
+    /// Consumes the scoped value by applying a closure to the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let len = new_linear!("text").scope(|scoped| scoped.consume(str::len));
+    /// assert_eq!(len, 4);
+    /// ```
+    pub fn consume<F: FnOnce(T) -> R, R>(self, f: F) -> Consumed<'s, R> {
+        Consumed {
+            value: self.linear.consume(f),
+            brand: PhantomData,
+        }
+    }
+
+    /// Consumes and destroys the scoped value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// new_linear!("text").scope(|scoped| scoped.destroy());
+    /// ```
+    pub fn destroy(self) -> Consumed<'s, ()> {
+        self.linear.destroy();
+        Consumed {
+            value: (),
+            brand: PhantomData,
+        }
+    }
+
+    /// Transforms the scoped value, the result stays within the scope.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let text = new_linear!(1).scope(|scoped| scoped.map(|x| x.to_string()).into());
+    /// assert_eq!(text, "1");
+    /// ```
+    pub fn map<F: FnOnce(T) -> R, R>(self, f: F) -> Scoped<'s, R, Linear<T, U>> {
+        Scoped {
+            linear: self.linear.map(f),
+            brand: self.brand,
+        }
+    }
+}
+
+impl<'s, R> Consumed<'s, R> {
+    /// Transforms the result carried by the proof.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let len = new_linear!("text").scope(|scoped| scoped.into().map(str::len));
+    /// assert_eq!(len, 4);
+    /// ```
+    pub fn map<F: FnOnce(R) -> R2, R2>(self, f: F) -> Consumed<'s, R2> {
+        Consumed {
+            value: f(self.value),
+            brand: self.brand,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn nested_scopes() {
+        let sum = crate::new_linear!(1).scope(|outer| {
+            let inner = crate::new_linear!(2).scope(|inner| inner.map(|x| x * 10).into());
+            outer.consume(|x| x + inner)
+        });
+        assert_eq!(sum, 21);
+    }
+}
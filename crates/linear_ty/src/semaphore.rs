@@ -0,0 +1,306 @@
+use crate::{__linear_from_parts, MustUse};
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+#[cfg(feature = "async")]
+use std::task::Waker;
+
+/// A semaphore handing out linear permits. A permit must be explicitly `release()`d, it is not
+/// released on drop, thus a leaked permit surfaces immediately instead of silently starving
+/// the system.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let semaphore = LinearSemaphore::new(1);
+/// let permit = semaphore.acquire();
+/// assert!(semaphore.try_acquire().is_none());
+/// permit.release();
+/// semaphore.try_acquire().unwrap().release();
+/// ```
+pub struct LinearSemaphore {
+    state: Mutex<State>,
+    available: Condvar,
+}
+
+struct State {
+    permits: usize,
+    // Wakers of pending `Acquire` futures by id, a future removes its entry when dropped.
+    #[cfg(feature = "async")]
+    waiters: VecDeque<(u64, Waker)>,
+    #[cfg(feature = "async")]
+    next_id: u64,
+}
+
+#[cfg(feature = "async")]
+impl State {
+    // Wakes the first waiter. Its entry is removed, the future registers again when it finds
+    // no permit.
+    fn wake_next(&mut self) -> Option<Waker> {
+        self.waiters.pop_front().map(|(_, waker)| waker)
+    }
+}
+
+/// Linear permit of a `LinearSemaphore`, it must be explicitly released.
+#[must_use]
+#[cfg_attr(
+    feature = "nightly",
+    must_not_suspend = "release the permit before the `.await`"
+)]
+pub struct Permit<'s>(MustUse<&'s LinearSemaphore>);
+
+impl LinearSemaphore {
+    /// Creates a semaphore with the given number of permits.
+    #[must_use]
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            state: Mutex::new(State {
+                permits,
+                #[cfg(feature = "async")]
+                waiters: VecDeque::new(),
+                #[cfg(feature = "async")]
+                next_id: 0,
+            }),
+            available: Condvar::new(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // The state is consistent between statements, a poisoned lock holds a valid state.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the number of available permits.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.state().permits
+    }
+
+    /// Acquires a permit, returns `None` when none is available.
+    pub fn try_acquire(&self) -> Option<Permit<'_>> {
+        let mut state = self.state();
+        state.permits = state.permits.checked_sub(1)?;
+        Some(Permit(__linear_from_parts(self)))
+    }
+
+    /// Acquires a permit, blocks until one is available.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let semaphore = LinearSemaphore::new(1);
+    /// std::thread::scope(|s| {
+    ///     let permit = semaphore.acquire();
+    ///     s.spawn(|| semaphore.acquire().release());
+    ///     permit.release();
+    /// });
+    /// assert_eq!(semaphore.available(), 1);
+    /// ```
+    pub fn acquire(&self) -> Permit<'_> {
+        let mut state = self.state();
+        while state.permits == 0 {
+            state = self
+                .available
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        state.permits -= 1;
+        Permit(__linear_from_parts(self))
+    }
+
+    /// Acquires a permit asynchronously, the returned future completes once one is available.
+    /// Available with the `async` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// let semaphore = LinearSemaphore::new(1);
+    /// let mut task = pin!(async {
+    ///     let permit = semaphore.acquire_async().await;
+    ///     permit.release();
+    /// });
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// ```
+    #[cfg(feature = "async")]
+    pub const fn acquire_async(&self) -> Acquire<'_> {
+        Acquire {
+            semaphore: self,
+            id: None,
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state();
+        state.permits += 1;
+        #[cfg(feature = "async")]
+        let waker = state.wake_next();
+        drop(state);
+        self.available.notify_one();
+        #[cfg(feature = "async")]
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Permit<'_> {
+    /// Releases the permit back to its semaphore.
+    pub fn release(self) {
+        self.0.into().release();
+    }
+}
+
+/// Future returned by `LinearSemaphore::acquire_async()`.
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless awaited"]
+pub struct Acquire<'s> {
+    semaphore: &'s LinearSemaphore,
+    id: Option<u64>,
+}
+
+#[cfg(feature = "async")]
+impl<'s> core::future::Future for Acquire<'s> {
+    type Output = Permit<'s>;
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Permit<'s>> {
+        let semaphore = self.semaphore;
+        let mut state = semaphore.state();
+        let entry = self
+            .id
+            .and_then(|id| state.waiters.iter().position(|(waiter, _)| *waiter == id));
+        if let Some(permits) = state.permits.checked_sub(1) {
+            state.permits = permits;
+            if let Some(entry) = entry {
+                state.waiters.remove(entry);
+            }
+            self.id = None;
+            core::task::Poll::Ready(Permit(__linear_from_parts(semaphore)))
+        } else {
+            if let Some(entry) = entry {
+                state.waiters[entry].1.clone_from(cx.waker());
+            } else {
+                let id = *self.id.get_or_insert_with(|| {
+                    state.next_id += 1;
+                    state.next_id
+                });
+                state.waiters.push_back((id, cx.waker().clone()));
+            }
+            core::task::Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(id) = self.id else {
+            return;
+        };
+        let mut state = self.semaphore.state();
+        let waker = match state.waiters.iter().position(|(waiter, _)| *waiter == id) {
+            Some(entry) => {
+                state.waiters.remove(entry);
+                None
+            }
+            // Woken but dropped before taking the permit, pass the wakeup on.
+            None if state.permits > 0 => state.wake_next(),
+            None => None,
+        };
+        drop(state);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearSemaphore;
+
+    #[test]
+    fn permits() {
+        let semaphore = LinearSemaphore::new(2);
+        let permits = [semaphore.acquire(), semaphore.acquire()];
+        assert_eq!(semaphore.available(), 0);
+        std::thread::scope(|s| {
+            let waiting = s.spawn(|| semaphore.acquire().release());
+            permits.into_iter().for_each(super::Permit::release);
+            waiting.join().unwrap();
+        });
+        assert_eq!(semaphore.available(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_permits() {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        let semaphore = LinearSemaphore::new(1);
+        let permit = semaphore.acquire();
+        let mut acquire = pin!(semaphore.acquire_async());
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(acquire.as_mut().poll(&mut cx).is_pending());
+        permit.release();
+        let Poll::Ready(permit) = acquire.poll(&mut cx) else {
+            panic!("permit released");
+        };
+        permit.release();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_dropped_waiter() {
+        use std::pin::pin;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::task::{Context, Wake, Waker};
+
+        struct Count(AtomicUsize);
+        impl Wake for Count {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let semaphore = LinearSemaphore::new(1);
+        let permit = semaphore.acquire();
+        let (stale, live) = (
+            Arc::new(Count(AtomicUsize::new(0))),
+            Arc::new(Count(AtomicUsize::new(0))),
+        );
+        let (stale_waker, live_waker) = (Waker::from(stale.clone()), Waker::from(live.clone()));
+        let mut stale_cx = Context::from_waker(&stale_waker);
+        let mut live_cx = Context::from_waker(&live_waker);
+        let mut stale_acquire = Box::pin(semaphore.acquire_async());
+        assert!(stale_acquire.as_mut().poll(&mut stale_cx).is_pending());
+        let mut live_acquire = pin!(semaphore.acquire_async());
+        assert!(live_acquire.as_mut().poll(&mut live_cx).is_pending());
+        assert!(live_acquire.as_mut().poll(&mut live_cx).is_pending());
+        drop(stale_acquire);
+        permit.release();
+        assert_eq!(stale.0.load(Ordering::Relaxed), 0);
+        assert_eq!(live.0.load(Ordering::Relaxed), 1);
+        let core::task::Poll::Ready(permit) = live_acquire.poll(&mut live_cx) else {
+            panic!("permit released");
+        };
+        permit.release();
+        assert!(semaphore.state().waiters.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let semaphore = LinearSemaphore::new(1);
+        let _ = semaphore.acquire();
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{__linear_from_parts, Linear};
+use core::any::Any;
+use core::marker::PhantomData;
+use std::sync::mpsc;
+
+/// Protocol step sending a `T`, continuing with the protocol `P`. Note that this shadows the
+/// `Send` marker trait when glob imported.
+pub struct Send<T, P>(PhantomData<fn() -> (T, P)>);
+
+/// Protocol step receiving a `T`, continuing with the protocol `P`.
+pub struct Recv<T, P>(PhantomData<fn() -> (T, P)>);
+
+/// End of a protocol.
+pub struct End;
+
+/// A session protocol with its dual, the protocol of the other endpoint.
+pub trait Session {
+    /// The protocol seen from the other endpoint.
+    type Dual: Session<Dual = Self>;
+}
+
+impl Session for End {
+    type Dual = End;
+}
+
+impl<T, P: Session> Session for Send<T, P> {
+    type Dual = Recv<T, P::Dual>;
+}
+
+impl<T, P: Session> Session for Recv<T, P> {
+    type Dual = Send<T, P::Dual>;
+}
+
+type Message = Box<dyn Any + core::marker::Send>;
+
+struct Endpoint {
+    tx: mpsc::Sender<Message>,
+    rx: mpsc::Receiver<Message>,
+}
+
+/// Linear endpoint of a session following the protocol `P`.
+#[must_use]
+pub struct Chan<P>(Linear<Endpoint, P>);
+
+/// Creates both endpoints of a session. The second endpoint follows the dual protocol.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::session::{self, End, Recv, Send};
+///
+/// type Ping = Send<u32, Recv<u32, End>>;
+///
+/// let (client, server) = session::channel::<Ping>();
+/// let handle = std::thread::spawn(move || {
+///     let (ping, server) = server.recv();
+///     server.send(ping + 1).close();
+/// });
+/// let (pong, client) = client.send(1).recv();
+/// client.close();
+/// handle.join().unwrap();
+/// assert_eq!(pong, 2);
+/// ```
+pub fn channel<P: Session>() -> (Chan<P>, Chan<P::Dual>) {
+    let (tx_a, rx_b) = mpsc::channel();
+    let (tx_b, rx_a) = mpsc::channel();
+    (
+        Chan(__linear_from_parts(Endpoint { tx: tx_a, rx: rx_a })),
+        Chan(__linear_from_parts(Endpoint { tx: tx_b, rx: rx_b })),
+    )
+}
+
+impl<T: core::marker::Send + 'static, P> Chan<Send<T, P>> {
+    /// Sends a value and continues with the rest of the protocol.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::session::{self, End, Send};
+    ///
+    /// let (client, server) = session::channel::<Send<&str, End>>();
+    /// client.send("hello").close();
+    /// let (hello, server) = server.recv();
+    /// server.close();
+    /// assert_eq!(hello, "hello");
+    /// ```
+    pub fn send(self, value: T) -> Chan<P> {
+        let endpoint = self.0.into();
+        // The peer endpoint is linear, it can only be gone after it was leaked. The value is
+        // lost then like with any other leaked receiver.
+        let _ = endpoint.tx.send(Box::new(value));
+        Chan(__linear_from_parts(endpoint))
+    }
+}
+
+impl<T: 'static, P> Chan<Recv<T, P>> {
+    /// Receives a value and continues with the rest of the protocol. Blocks until the peer
+    /// sent the value.
+    ///
+    /// # Panics
+    ///
+    /// When the peer endpoint was leaked without sending the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::session::{self, End, Recv};
+    ///
+    /// let (server, client) = session::channel::<Recv<u8, End>>();
+    /// client.send(7).close();
+    /// let (value, server) = server.recv();
+    /// server.close();
+    /// assert_eq!(value, 7);
+    /// ```
+    pub fn recv(self) -> (T, Chan<P>) {
+        let endpoint = self.0.into();
+        let message = endpoint.rx.recv().expect("session peer leaked");
+        let value = *message
+            .downcast::<T>()
+            .unwrap_or_else(|_| unreachable!("session protocols are dual"));
+        (value, Chan(__linear_from_parts(endpoint)))
+    }
+}
+
+impl Chan<End> {
+    /// Closes the endpoint at the end of the protocol.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::session::{self, End};
+    ///
+    /// let (a, b) = session::channel::<End>();
+    /// a.close();
+    /// b.close();
+    /// ```
+    pub fn close(self) {
+        self.0.destroy();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{End, Recv, Send};
+
+    type Protocol = Send<String, Recv<usize, Send<bool, End>>>;
+
+    #[test]
+    fn protocol() {
+        let (client, server) = super::channel::<Protocol>();
+        let handle = std::thread::spawn(move || {
+            let (text, server) = server.recv();
+            let (ok, server) = server.send(text.len()).recv();
+            server.close();
+            ok
+        });
+        let (len, client) = client.send(String::from("four")).recv();
+        client.send(len == 4).close();
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let (client, server) = super::channel::<End>();
+        client.close();
+        drop(server);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{__linear_from_parts, Linear};
+use std::sync::Arc;
+
+/// A linear read-only share of a value split by `Linear::share()`. All shares of a value must
+/// be joined back before the value can be consumed again, every worker of a scatter/gather
+/// workflow has to report back.
+///
+/// Reading the shared value requires the `semipure` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut shares = new_linear!(vec![1, 2, 3]).share(3);
+/// let workers: Vec<_> = shares
+///     .drain(1..)
+///     .map(|share| std::thread::spawn(move || share))
+///     .collect();
+/// let share = workers
+///     .into_iter()
+///     .fold(shares.pop().unwrap(), |share, worker| share.join(worker.join().unwrap()));
+/// let linear = share.into_linear().ok().unwrap();
+/// assert_eq!(linear.into(), vec![1, 2, 3]);
+/// ```
+#[must_use]
+pub struct Share<T, U>(Linear<Arc<T>, U>);
+
+impl<T, U> Linear<T, U> {
+    /// Splits the linear value into `n` read-only shares.
+    ///
+    /// # Panics
+    ///
+    /// When `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let shares = new_linear!(1).share(2);
+    /// assert_eq!(shares.len(), 2);
+    /// let share = shares.into_iter().reduce(Share::join).unwrap();
+    /// assert_eq!(share.into_linear().ok().unwrap().into(), 1);
+    /// ```
+    pub fn share(self, n: usize) -> Vec<Share<T, U>> {
+        assert!(n > 0, "a linear value is split into at least one share");
+        let value = Arc::new(self.into());
+        let mut shares = Vec::with_capacity(n);
+        for _ in 1..n {
+            shares.push(Share(__linear_from_parts(Arc::clone(&value))));
+        }
+        shares.push(Share(__linear_from_parts(value)));
+        shares
+    }
+}
+
+impl<T, U> Share<T, U> {
+    /// Joins another share of the same value into this one.
+    ///
+    /// # Panics
+    ///
+    /// When the shares were split from different values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut shares = new_linear!("value").share(2);
+    /// let share = shares.pop().unwrap().join(shares.pop().unwrap());
+    /// assert_eq!(share.shares(), 1);
+    /// # share.into_linear().ok().unwrap().into();
+    /// ```
+    pub fn join(self, other: Self) -> Self {
+        let other = other.0.into();
+        assert!(
+            Arc::ptr_eq(self.0.get_arc(), &other),
+            "shares of different values joined"
+        );
+        self
+    }
+
+    /// Returns the number of shares not joined yet, this one included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let shares = new_linear!(()).share(3);
+    /// assert_eq!(shares[0].shares(), 3);
+    /// # shares.into_iter().reduce(Share::join).unwrap().into_linear().ok().unwrap().destroy();
+    /// ```
+    pub fn shares(&self) -> usize {
+        Arc::strong_count(self.0.get_arc())
+    }
+
+    /// Returns the linear value once all shares are joined into this one, otherwise returns the
+    /// share back.
+    ///
+    /// # Errors
+    ///
+    /// When other shares of the value are still outstanding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut shares = new_linear!(5).share(2);
+    /// let share = shares.pop().unwrap();
+    /// let share = share.into_linear().err().unwrap();
+    /// let linear = share.join(shares.pop().unwrap()).into_linear().ok().unwrap();
+    /// assert_eq!(linear.into(), 5);
+    /// ```
+    pub fn into_linear(self) -> Result<Linear<T, U>, Self> {
+        Arc::try_unwrap(self.0.into()).map_or_else(
+            |shared| Err(Self(__linear_from_parts(shared))),
+            |value| Ok(__linear_from_parts(value)),
+        )
+    }
+
+    #[cfg(any(doc, feature = "semipure"))]
+    /// Returns a reference to the shared value.
+    ///
+    /// # Safety
+    ///
+    /// This is as impure as `Linear::get_ref()`. The reference must not be used to clone the
+    /// value or to mutate it through interior mutability. It is only available with the
+    /// `semipure` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let shares = new_linear!(7).share(2);
+    /// # #[cfg(feature = "semipure")]
+    /// assert_eq!(unsafe { shares[1].get() }, &7);
+    /// # shares.into_iter().reduce(Share::join).unwrap().into_linear().ok().unwrap().into();
+    /// ```
+    pub unsafe fn get(&self) -> &T {
+        self.0.get_arc()
+    }
+}
+
+impl<T, U> Linear<Arc<T>, U> {
+    fn get_arc(&self) -> &Arc<T> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Share;
+
+    #[test]
+    fn joined() {
+        let shares = crate::new_linear!(String::from("data")).share(4);
+        let share = shares.into_iter().reduce(Share::join).unwrap();
+        assert_eq!(share.shares(), 1);
+        assert_eq!(share.into_linear().ok().unwrap().into(), "data");
+    }
+
+    #[test]
+    #[should_panic(expected = "shares of different values joined")]
+    fn different_values() {
+        // values of the same type, `new_linear!` values would not even compile
+        let a = crate::must_use(1).share(1).pop().unwrap();
+        let b = crate::must_use(1).share(1).pop().unwrap();
+        let _ = a.join(b);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = crate::new_linear!(1).share(2);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{__linear_from_parts, MustUse};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Source of the ids tying keys to their slab.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A slab storing values under linear keys. Every key handed out by `insert()` must be given
+/// back to `remove()`, the slab is linear itself and can only be finished once all keys are
+/// consumed. Models id based resource registries where leaked keys are bugs.
+///
+/// # Panics
+///
+/// Keys of another slab panic when used.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut handles = Slab::new();
+/// let fd = handles.insert("socket");
+/// let gpu = handles.insert("texture");
+/// assert_eq!(handles.get(&fd), &"socket");
+/// assert_eq!(handles.remove(gpu), "texture");
+/// assert_eq!(handles.remove(fd), "socket");
+/// assert!(handles.finish().is_ok());
+/// ```
+#[must_use]
+pub struct Slab<T>(MustUse<Entries<T>>);
+
+struct Entries<T> {
+    id: usize,
+    slots: Vec<Option<T>>,
+    free: Vec<usize>,
+}
+
+/// Linear key of a value stored in a `Slab`.
+#[must_use]
+pub struct SlabKey<T>(MustUse<(usize, usize)>, PhantomData<fn() -> T>);
+
+impl<T> Slab<T> {
+    /// Creates an empty slab.
+    pub fn new() -> Self {
+        Self(__linear_from_parts(Entries {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            slots: Vec::new(),
+            free: Vec::new(),
+        }))
+    }
+
+    /// Returns the number of stored values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.0.slots.len() - self.0.0.free.len()
+    }
+
+    /// Returns whether the slab holds no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stores a value and returns its key.
+    pub fn insert(&mut self, value: T) -> SlabKey<T> {
+        let entries = &mut *self.0.0;
+        let index = if let Some(index) = entries.free.pop() {
+            entries.slots[index] = Some(value);
+            index
+        } else {
+            entries.slots.push(Some(value));
+            entries.slots.len() - 1
+        };
+        SlabKey(__linear_from_parts((entries.id, index)), PhantomData)
+    }
+
+    fn index(&self, key: &SlabKey<T>) -> usize {
+        let (id, index) = *key.0.0;
+        assert_eq!(id, self.0.0.id, "key of another slab");
+        index
+    }
+
+    /// Returns a reference to the value of a key.
+    ///
+    /// # Panics
+    ///
+    /// When the key belongs to another slab.
+    pub fn get(&self, key: &SlabKey<T>) -> &T {
+        let index = self.index(key);
+        self.0.0.slots[index]
+            .as_ref()
+            .unwrap_or_else(|| unreachable!("keys are linear"))
+    }
+
+    /// Returns a mutable reference to the value of a key.
+    ///
+    /// # Panics
+    ///
+    /// When the key belongs to another slab.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut slab = Slab::new();
+    /// let key = slab.insert(1);
+    /// *slab.get_mut(&key) += 1;
+    /// assert_eq!(slab.remove(key), 2);
+    /// # slab.finish().ok().unwrap();
+    /// ```
+    pub fn get_mut(&mut self, key: &SlabKey<T>) -> &mut T {
+        let index = self.index(key);
+        self.0.0.slots[index]
+            .as_mut()
+            .unwrap_or_else(|| unreachable!("keys are linear"))
+    }
+
+    /// Removes the value of a key, consuming the key.
+    ///
+    /// # Panics
+    ///
+    /// When the key belongs to another slab.
+    pub fn remove(&mut self, key: SlabKey<T>) -> T {
+        let index = self.index(&key);
+        key.0.destroy();
+        let entries = &mut *self.0.0;
+        entries.free.push(index);
+        entries.slots[index]
+            .take()
+            .unwrap_or_else(|| unreachable!("keys are linear"))
+    }
+
+    /// Finishes the slab once all keys are consumed.
+    ///
+    /// # Errors
+    ///
+    /// When values are left the slab is returned as `Err`.
+    pub fn finish(self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.0.destroy();
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slab;
+
+    #[test]
+    fn reuses_slots() {
+        let mut slab = Slab::new();
+        let a = slab.insert('a');
+        let b = slab.insert('b');
+        assert_eq!(slab.remove(a), 'a');
+        let c = slab.insert('c');
+        assert_eq!(slab.len(), 2);
+        assert_eq!((slab.get(&b), slab.get(&c)), (&'b', &'c'));
+        let mut slab = slab.finish().err().unwrap();
+        slab.remove(b);
+        slab.remove(c);
+        slab.finish().ok().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "key of another slab")]
+    fn other_slab() {
+        let mut a = Slab::new();
+        let b = Slab::<u8>::new();
+        let key = a.insert(1);
+        let _ = b.get(&key);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn key_dropped() {
+        let mut slab = Slab::new();
+        let _ = slab.insert(1);
+    }
+}
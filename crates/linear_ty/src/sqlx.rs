@@ -0,0 +1,117 @@
+use crate::DropBomb;
+use sqlx::{Database, Transaction};
+
+/// A sqlx transaction whose only exits are `commit()` and `rollback()`, both returning the
+/// error of the driver. Dropping it aborts with "transaction neither committed nor rolled
+/// back" instead of scheduling a silent rollback.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::sqlx::LinearSqlxTransaction;
+/// use sqlx::Connection;
+///
+/// # #[cfg(feature = "sqlx-sqlite")]
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:").await?;
+/// sqlx::query("CREATE TABLE kv (k TEXT, v TEXT)").execute(&mut conn).await?;
+/// let mut tx = LinearSqlxTransaction::new(conn.begin().await?);
+/// sqlx::query("INSERT INTO kv VALUES ('a', 'b')")
+///     .execute(&mut **tx.get_mut())
+///     .await?;
+/// tx.commit().await?;
+/// # Ok::<(), sqlx::Error>(())
+/// # }).unwrap();
+/// ```
+#[must_use]
+pub struct LinearSqlxTransaction<'c, DB: Database> {
+    tx: Transaction<'c, DB>,
+    bomb: DropBomb,
+}
+
+impl<'c, DB: Database> LinearSqlxTransaction<'c, DB> {
+    /// Wraps a transaction.
+    pub const fn new(tx: Transaction<'c, DB>) -> Self {
+        Self {
+            tx,
+            bomb: DropBomb::new("transaction neither committed nor rolled back"),
+        }
+    }
+
+    /// Returns the transaction, to run its statements.
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut Transaction<'c, DB> {
+        &mut self.tx
+    }
+
+    /// Commits the transaction.
+    ///
+    /// # Errors
+    ///
+    /// When committing fails.
+    pub async fn commit(self) -> Result<(), sqlx::Error> {
+        self.bomb.defuse();
+        self.tx.commit().await
+    }
+
+    /// Rolls the transaction back.
+    ///
+    /// # Errors
+    ///
+    /// When rolling back fails.
+    pub async fn rollback(self) -> Result<(), sqlx::Error> {
+        self.bomb.defuse();
+        self.tx.rollback().await
+    }
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite"))]
+mod tests {
+    use super::LinearSqlxTransaction;
+    use sqlx::{Connection, SqliteConnection};
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn committed_and_rolled_back() {
+        runtime().block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE t (x INTEGER)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            for (value, commit) in [(1, true), (2, false)] {
+                let mut tx = LinearSqlxTransaction::new(conn.begin().await.unwrap());
+                sqlx::query("INSERT INTO t VALUES (?)")
+                    .bind(value)
+                    .execute(&mut **tx.get_mut())
+                    .await
+                    .unwrap();
+                if commit {
+                    tx.commit().await.unwrap();
+                } else {
+                    tx.rollback().await.unwrap();
+                }
+            }
+            let values: Vec<i64> = sqlx::query_scalar("SELECT x FROM t")
+                .fetch_all(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(values, [1]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction neither committed nor rolled back")]
+    fn dropped() {
+        runtime().block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            let _ = LinearSqlxTransaction::new(conn.begin().await.unwrap());
+        });
+    }
+}
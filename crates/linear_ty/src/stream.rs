@@ -0,0 +1,283 @@
+use crate::{__linear_from_parts, __linearity, Linear, Linearity};
+use core::future::{Future, poll_fn};
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll};
+use futures_core::Stream;
+
+/// Consuming adapters for streams yielding `Linear<T>` items. Every item is consumed exactly
+/// once, none is dropped along the way.
+pub trait LinearStreamExt<T, U>: Stream<Item = Linear<T, U>> + Sized {
+    /// Consumes each item with an async closure until the stream is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// # struct Items(Vec<MustUse<i32>>);
+    /// # impl futures_core::Stream for Items {
+    /// #     type Item = MustUse<i32>;
+    /// #     fn poll_next(
+    /// #         mut self: std::pin::Pin<&mut Self>,
+    /// #         _: &mut Context<'_>,
+    /// #     ) -> Poll<Option<MustUse<i32>>> {
+    /// #         Poll::Ready(self.0.pop())
+    /// #     }
+    /// # }
+    /// let mut sum = 0;
+    /// let task = Items(vec![must_use(1), must_use(2)]).for_each_consume(|x| {
+    ///     sum += x;
+    ///     async {}
+    /// });
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(pin!(task).poll(&mut cx), Poll::Ready(()));
+    /// assert_eq!(sum, 3);
+    /// ```
+    fn for_each_consume<F, Fut>(self, mut f: F) -> impl Future<Output = ()>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                f(item.into()).await;
+            }
+        }
+    }
+
+    /// Consumes each item with a closure, yielding the linear results. Like
+    /// `Linear::drain_consume()` the items stay linear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// # struct Items(Vec<MustUse<&'static str>>);
+    /// # impl futures_core::Stream for Items {
+    /// #     type Item = MustUse<&'static str>;
+    /// #     fn poll_next(
+    /// #         mut self: std::pin::Pin<&mut Self>,
+    /// #         _: &mut Context<'_>,
+    /// #     ) -> Poll<Option<MustUse<&'static str>>> {
+    /// #         Poll::Ready(self.0.pop())
+    /// #     }
+    /// # }
+    /// let mut lengths = Vec::new();
+    /// let stream = Items(vec![must_use("a"), must_use("bb")]).map_consume(str::len);
+    /// let task = stream.for_each_consume(|len| {
+    ///     lengths.push(len);
+    ///     async {}
+    /// });
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(pin!(task).poll(&mut cx), Poll::Ready(()));
+    /// assert_eq!(lengths, [2, 1]);
+    /// ```
+    fn map_consume<F: FnMut(T) -> R, R>(self, f: F) -> MapConsume<Self, F> {
+        MapConsume { stream: self, f }
+    }
+
+    /// Collects the `Ok` values of the items into a linear vector, stopping at the first `Err`
+    /// like `Linear::collect_ok()`. The `Ok` values collected so far and all remaining items
+    /// are dropped as plain values then, the stream is still run to completion. The linearity
+    /// is carried on by the returned `Linear<Result<_,E>>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// # struct Items(Vec<MustUse<Result<i32, ()>>>);
+    /// # impl futures_core::Stream for Items {
+    /// #     type Item = MustUse<Result<i32, ()>>;
+    /// #     fn poll_next(
+    /// #         mut self: std::pin::Pin<&mut Self>,
+    /// #         _: &mut Context<'_>,
+    /// #     ) -> Poll<Option<MustUse<Result<i32, ()>>>> {
+    /// #         Poll::Ready(self.0.pop())
+    /// #     }
+    /// # }
+    /// let mut task = pin!(Items(vec![must_use(Ok(1)), must_use(Ok(2))]).try_collect_linear());
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let Poll::Ready(collected) = task.as_mut().poll(&mut cx) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(collected.unwrap_ok().into(), vec![2, 1]);
+    /// ```
+    fn try_collect_linear<A, E>(
+        self,
+    ) -> impl Future<Output = Linear<Result<Vec<A>, E>, Linear<T, U>>>
+    where
+        T: Into<Result<A, E>>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut collected = Ok(Vec::new());
+            while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                let item = item.into().into();
+                if let Ok(values) = &mut collected {
+                    match item {
+                        Ok(value) => values.push(value),
+                        Err(error) => collected = Err(error),
+                    }
+                }
+            }
+            __linear_from_parts(collected)
+        }
+    }
+}
+
+impl<S: Stream<Item = Linear<T, U>>, T, U> LinearStreamExt<T, U> for S {}
+
+/// Stream returned by `LinearStreamExt::map_consume()`.
+#[must_use = "streams do nothing unless polled"]
+pub struct MapConsume<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F, T, U, R> Stream for MapConsume<S, F>
+where
+    S: Stream<Item = Linear<T, U>>,
+    F: FnMut(T) -> R,
+{
+    type Item = Linear<R, Linear<T, U>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `stream` is structurally pinned, it is never moved out of a pinned
+        // `MapConsume`, which has no `Drop` implementation either. `f` is not pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let item = unsafe { Pin::new_unchecked(&mut this.stream) }.poll_next(cx);
+        item.map(|item| item.map(|item| __linear_from_parts((this.f)(item.into()))))
+    }
+}
+
+/// A stream which must be run to completion, until it yielded `None`. Dropping it before is a
+/// linearity violation, `cancel()` is the explicit cancellation path.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// # use std::pin::Pin;
+/// # use std::task::{Context, Poll, Waker};
+/// # use futures_core::Stream;
+/// # struct Count(u8);
+/// # impl Stream for Count {
+/// #     type Item = u8;
+/// #     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u8>> {
+/// #         self.0 = self.0.saturating_sub(1);
+/// #         Poll::Ready((self.0 > 0).then_some(self.0))
+/// #     }
+/// # }
+/// let mut stream = LinearStream::new(Count(3));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(2)));
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(1)));
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct LinearStream<S> {
+    stream: S,
+    linearity: Option<Linearity<S>>,
+}
+
+impl<S> LinearStream<S> {
+    /// Wraps a stream which must be run to completion.
+    pub const fn new(stream: S) -> Self {
+        Self {
+            stream,
+            linearity: Some(__linearity::<S>()),
+        }
+    }
+
+    /// Cancels the stream and returns it. A stream which is not `Unpin` can only be moved out
+    /// before it is pinned, thus before it was polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// # struct Never;
+    /// # impl futures_core::Stream for Never {
+    /// #     type Item = ();
+    /// #     fn poll_next(
+    /// #         self: std::pin::Pin<&mut Self>,
+    /// #         _: &mut std::task::Context<'_>,
+    /// #     ) -> std::task::Poll<Option<()>> {
+    /// #         std::task::Poll::Pending
+    /// #     }
+    /// # }
+    /// let stream = LinearStream::new(Never);
+    /// let _ = stream.cancel();
+    /// ```
+    pub fn cancel(mut self) -> S {
+        core::mem::forget(self.linearity.take());
+        self.stream
+    }
+}
+
+impl<S: Stream> Stream for LinearStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        // SAFETY: `stream` is structurally pinned, it is never moved out of a pinned
+        // `LinearStream`, which has no `Drop` implementation either.
+        let this = unsafe { self.get_unchecked_mut() };
+        let poll = unsafe { Pin::new_unchecked(&mut this.stream) }.poll_next(cx);
+        if let Poll::Ready(None) = poll {
+            core::mem::forget(this.linearity.take());
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearStream, LinearStreamExt};
+    use crate::{MustUse, must_use};
+    use core::future::Future;
+    use core::pin::{Pin, pin};
+    use core::task::{Context, Poll, Waker};
+    use futures_core::Stream;
+
+    struct Items(Vec<MustUse<Result<u8, &'static str>>>);
+
+    impl Stream for Items {
+        type Item = MustUse<Result<u8, &'static str>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.pop())
+        }
+    }
+
+    #[test]
+    fn try_collect_err() {
+        let items = Items(vec![must_use(Ok(1)), must_use(Err("bad")), must_use(Ok(2))]);
+        let mut task = pin!(LinearStream::new(items).try_collect_linear());
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(collected) = task.as_mut().poll(&mut cx) else {
+            panic!("stream is ready");
+        };
+        assert_eq!(collected.unwrap_err().into(), "bad");
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn stream_dropped() {
+        let mut stream = LinearStream::new(Items(vec![must_use(Ok(1)), must_use(Ok(2))]));
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(Some(item)) = Pin::new(&mut stream).poll_next(&mut cx) else {
+            panic!("stream is ready");
+        };
+        item.destroy();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{__linear_from_parts, MustUse};
+
+/// A relevant value, it must be used at least once. It may be observed and cloned any number
+/// of times, but every copy must be explicitly finished and is never implicitly dropped.
+///
+/// `Linear` values are used exactly once, `Relevant` values at least once and `Affine` values
+/// at most once.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let report = Relevant::new(String::from("report"));
+/// let copy = report.clone();
+/// assert_eq!(report.get(), copy.get());
+/// assert_eq!(report.finish(), copy.finish());
+/// ```
+#[must_use]
+pub struct Relevant<T>(MustUse<T>);
+
+impl<T> Relevant<T> {
+    /// Wraps a value which must be used at least once.
+    pub const fn new(value: T) -> Self {
+        Self(__linear_from_parts(value))
+    }
+
+    /// Returns a reference to the inner value, a relevant value may be observed any number of
+    /// times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let relevant = Relevant::new(1);
+    /// assert_eq!(relevant.get(), &1);
+    /// # relevant.finish();
+    /// ```
+    pub fn get(&self) -> &T {
+        &self.0.0
+    }
+
+    /// Finishes the relevant value and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// assert_eq!(Relevant::new(1).finish(), 1);
+    /// ```
+    pub fn finish(self) -> T {
+        self.0.into()
+    }
+}
+
+impl<T: Clone> Clone for Relevant<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.get().clone())
+    }
+}
+
+/// An affine value, it may be used at most once. Unlike a `Linear` value it may be discarded,
+/// but only explicitly with `discard()`, it is never implicitly dropped.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let retry = Affine::new("retry");
+/// retry.discard();
+/// let request = Affine::new("request").map(str::len);
+/// assert_eq!(request.into(), 7);
+/// ```
+#[must_use]
+pub struct Affine<T>(MustUse<T>);
+
+impl<T> Affine<T> {
+    /// Wraps a value which may be used at most once.
+    pub const fn new(value: T) -> Self {
+        Self(__linear_from_parts(value))
+    }
+
+    /// Uses the affine value and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// assert_eq!(Affine::new(1).into(), 1);
+    /// ```
+    pub fn into(self) -> T {
+        self.0.into()
+    }
+
+    /// Transforms the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// assert_eq!(Affine::new(1).map(|x| x + 1).into(), 2);
+    /// ```
+    pub fn map<F: FnOnce(T) -> R, R>(self, f: F) -> Affine<R> {
+        Affine::new(f(self.into()))
+    }
+
+    /// Explicitly discards the affine value, the inner value is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// Affine::new(vec![1, 2, 3]).discard();
+    /// ```
+    pub fn discard(self) {
+        drop(self.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Affine, Relevant};
+
+    #[test]
+    fn relevant_copies() {
+        let relevant = Relevant::new(vec![1]);
+        let copies: Vec<_> = (0..3).map(|_| relevant.clone()).collect();
+        assert_eq!(relevant.finish(), [1]);
+        assert_eq!(copies.into_iter().map(Relevant::finish).count(), 3);
+    }
+
+    #[test]
+    fn affine_discarded() {
+        let dropped = std::rc::Rc::new(());
+        Affine::new(std::rc::Rc::clone(&dropped)).discard();
+        assert_eq!(std::rc::Rc::strong_count(&dropped), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn relevant_dropped() {
+        let _ = Relevant::new(());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn affine_dropped() {
+        let _ = Affine::new(());
+    }
+}
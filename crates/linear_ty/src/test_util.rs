@@ -0,0 +1,94 @@
+pub use trybuild;
+
+/// Runs the trybuild compile fail tests matching `pattern`. Trybuild builds them with its own
+/// flags, ignoring `RUSTFLAGS`, thus a test file denies warnings with `#![deny(warnings)]` for
+/// a linear value that is not used to fail to compile as well. Each test file needs a `.stderr`
+/// file next to it holding the expected compiler output, run with `TRYBUILD=overwrite` to
+/// generate it.
+///
+/// A test file is a small binary misusing a linear type:
+///
+/// ```text
+/// #![deny(warnings)]
+///
+/// use linear_ty::*;
+///
+/// fn main() {
+///     let foo = new_linear!("foo");
+///     let mut bar = new_linear!("bar");
+///     // must not compile, every linear value has its own type
+///     bar = foo;
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// linear_ty::test_util::compile_fail("tests/compile_fail/*.rs");
+/// ```
+pub fn compile_fail(pattern: &str) {
+    let t = trybuild::TestCases::new();
+    t.compile_fail(pattern);
+}
+
+/// Generates a `#[test]` running the compile fail tests matching a pattern, see
+/// `compile_fail()`.
+///
+/// # Example
+///
+/// ```rust
+/// linear_ty::compile_fail_test!(ui_compile_fail, "tests/compile_fail/*.rs");
+/// ```
+#[macro_export]
+macro_rules! compile_fail_test {
+    ($name:ident, $pattern:expr) => {
+        #[test]
+        fn $name() {
+            $crate::test_util::compile_fail($pattern);
+        }
+    };
+}
+
+/// Asserts at compile time that a type can not be cloned or copied, which would allow to make
+/// up linear values from thin air.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// linear! {
+///     /// Linear token.
+///     pub struct Token(u64);
+/// }
+///
+/// assert_linear!(Token);
+/// assert_linear!(MustUse<String>);
+/// ```
+///
+/// ```compile_fail
+/// use linear_ty::*;
+///
+/// assert_linear!(String);
+/// ```
+#[macro_export]
+macro_rules! assert_linear {
+    ($ty:ty) => {
+        const _: fn() = || {
+            // Inferring the parameter is ambiguous when `$ty` implements `Clone`.
+            trait AmbiguousIfClone<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfClone<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + ::core::clone::Clone> AmbiguousIfClone<Invalid> for T {}
+            let _ = <$ty as AmbiguousIfClone<_>>::some_item;
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    assert_linear!(crate::LinearString);
+    assert_linear!(crate::Linear<u8, crate::UniqueType<fn()>>);
+}
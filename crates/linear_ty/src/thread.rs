@@ -0,0 +1,195 @@
+use crate::{__linear_from_parts, DropBomb, Linear, MustUse};
+
+/// Linear handle of a thread spawned by `spawn()`. It must be either `join()`ed or explicitly
+/// `detach()`ed, forgetting to join a worker thread loses its panic and hangs shutdown.
+#[must_use]
+pub struct LinearJoinHandle<T>(MustUse<std::thread::JoinHandle<T>>);
+
+/// Spawns a thread like `std::thread::spawn()` and returns its linear handle.
+///
+/// # Panics
+///
+/// When the thread can not be spawned, like `std::thread::spawn()`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::thread;
+///
+/// let worker = thread::spawn(|| 6 * 7);
+/// assert_eq!(worker.join().unwrap(), 42);
+/// ```
+pub fn spawn<F, T>(f: F) -> LinearJoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    LinearJoinHandle(__linear_from_parts(std::thread::spawn(f)))
+}
+
+/// Additional `spawn_consume()` method for any `Linear<T>`.
+impl<T: Send + 'static, U> Linear<T, U> {
+    /// Moves the inner value onto a new thread and consumes it there with a closure. The value
+    /// is consumed on the worker or the process aborts, a panic of the closure is not a way out.
+    /// The returned handle is linear as well.
+    ///
+    /// # Panics or Aborts
+    ///
+    /// Aborts when the closure panics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let job = new_linear!(vec![1, 2, 3]);
+    /// let worker = job.spawn_consume(|items| items.iter().sum::<i32>());
+    /// assert_eq!(worker.join().unwrap(), 6);
+    /// ```
+    pub fn spawn_consume<F, R>(self, f: F) -> LinearJoinHandle<R>
+    where
+        F: FnOnce(T) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let inner = self.into();
+        spawn(move || {
+            let bomb = DropBomb::new("spawn_consume() worker panicked while consuming the value");
+            let result = f(inner);
+            bomb.defuse();
+            result
+        })
+    }
+}
+
+impl<T> LinearJoinHandle<T> {
+    /// Waits for the thread to finish and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// When the thread panicked, the panic payload is returned.
+    pub fn join(self) -> std::thread::Result<T> {
+        self.0.into().join()
+    }
+
+    /// Detaches the thread, it keeps running on its own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::thread;
+    ///
+    /// thread::spawn(|| ()).detach();
+    /// ```
+    pub fn detach(self) {
+        drop(self.0.into());
+    }
+
+    /// Returns whether the thread has finished running.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.0.0.is_finished()
+    }
+
+    /// Returns the handle of the thread.
+    #[must_use]
+    pub fn thread(&self) -> &std::thread::Thread {
+        self.0.0.thread()
+    }
+}
+
+/// Scope of `linear_scope()` spawning scoped threads with linear handles.
+pub struct LinearScope<'scope, 'env: 'scope>(&'scope std::thread::Scope<'scope, 'env>);
+
+/// Linear handle of a thread spawned in a `linear_scope()`. It can not escape the scope and
+/// must be joined inside it.
+#[must_use]
+pub struct LinearScopedJoinHandle<'scope, T>(MustUse<std::thread::ScopedJoinHandle<'scope, T>>);
+
+/// Runs a closure in a `std::thread::scope()` spawning threads with linear handles. Every
+/// handle must be joined inside the scope, combining structured concurrency with linearity.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::thread;
+///
+/// let data = vec![1, 2, 3, 4];
+/// let sum = thread::linear_scope(|s| {
+///     let (left, right) = data.split_at(2);
+///     let left = s.spawn(|| left.iter().sum::<i32>());
+///     let right = s.spawn(|| right.iter().sum::<i32>());
+///     left.join().unwrap() + right.join().unwrap()
+/// });
+/// assert_eq!(sum, 10);
+/// ```
+///
+/// Handles can not escape the scope:
+///
+/// ```compile_fail
+/// use linear_ty::thread;
+///
+/// let handle = thread::linear_scope(|s| s.spawn(|| 1));
+/// ```
+pub fn linear_scope<'env, F, R>(f: F) -> R
+where
+    F: for<'scope> FnOnce(&LinearScope<'scope, 'env>) -> R,
+{
+    std::thread::scope(|s| f(&LinearScope(s)))
+}
+
+impl<'scope> LinearScope<'scope, '_> {
+    /// Spawns a scoped thread and returns its linear handle.
+    pub fn spawn<F, T>(&self, f: F) -> LinearScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        LinearScopedJoinHandle(__linear_from_parts(self.0.spawn(f)))
+    }
+}
+
+impl<T> LinearScopedJoinHandle<'_, T> {
+    /// Waits for the thread to finish and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// When the thread panicked, the panic payload is returned.
+    pub fn join(self) -> std::thread::Result<T> {
+        self.0.into().join()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{linear_scope, spawn};
+
+    #[test]
+    fn joined() {
+        let worker = spawn(|| String::from("done"));
+        assert_ne!(worker.thread().id(), std::thread::current().id());
+        assert_eq!(worker.join().unwrap(), "done");
+        let panicked = spawn(|| panic!("worker failed"));
+        assert!(panicked.join().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = spawn(|| ());
+    }
+
+    #[test]
+    fn spawn_consume() {
+        let worker = crate::new_linear!(String::from("payload")).spawn_consume(|s| s.len());
+        assert_eq!(worker.join().unwrap(), 7);
+    }
+
+    #[test]
+    fn scoped() {
+        let mut results = Vec::new();
+        linear_scope(|s| {
+            let handles: Vec<_> = (0..3).map(|i| s.spawn(move || i * 2)).collect();
+            results.extend(handles.into_iter().map(|h| h.join().unwrap()));
+        });
+        assert_eq!(results, [0, 2, 4]);
+    }
+}
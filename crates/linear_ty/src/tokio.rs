@@ -0,0 +1,91 @@
+use crate::LinearFuture;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use tokio::task::{JoinError, JoinHandle};
+
+/// Linear handle of a tokio task spawned by `spawn_linear()`. It must be awaited to completion
+/// or explicitly aborted with `abort_and_forget()`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::tokio::spawn_linear;
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let answer = runtime.block_on(async {
+///     let task = spawn_linear(async { 6 * 7 });
+///     task.await.unwrap()
+/// });
+/// assert_eq!(answer, 42);
+/// ```
+#[must_use = "futures do nothing unless awaited"]
+pub struct LinearJoinHandle<T>(LinearFuture<JoinHandle<T>>);
+
+/// Spawns a task like `tokio::spawn()` and returns its linear handle.
+///
+/// # Panics
+///
+/// When called outside of a tokio runtime, like `tokio::spawn()`.
+pub fn spawn_linear<F>(future: F) -> LinearJoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    LinearJoinHandle(LinearFuture::new(tokio::spawn(future)))
+}
+
+impl<T> LinearJoinHandle<T> {
+    /// Aborts the task and gives up its result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use linear_ty::tokio::spawn_linear;
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// runtime.block_on(async {
+    ///     spawn_linear(std::future::pending::<()>()).abort_and_forget();
+    /// });
+    /// ```
+    pub fn abort_and_forget(self) {
+        self.0.into_inner_unpolled().abort();
+    }
+}
+
+impl<T> Future for LinearJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spawn_linear;
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn awaited() {
+        let result = runtime().block_on(async {
+            let task = spawn_linear(async { "done" });
+            let failed = spawn_linear(async { panic!("task failed") });
+            (task.await.unwrap(), failed.await.is_err())
+        });
+        assert_eq!(result, ("done", true));
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        runtime().block_on(async {
+            drop(spawn_linear(async {}));
+        });
+    }
+}
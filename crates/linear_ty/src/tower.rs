@@ -0,0 +1,139 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+crate::linear! {
+    /// Response of a `LinearService`. Downstream layers and handlers must explicitly consume
+    /// or forward it, a middleware dropping a response on an error path is caught.
+    pub struct LinearResponse<Res>(Res);
+}
+
+/// Layer wrapping the responses of a service in `LinearResponse`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::tower::{LinearLayer, LinearResponse};
+/// # use std::convert::Infallible;
+/// # use std::future::{Ready, ready};
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// use tower_layer::Layer;
+/// use tower_service::Service;
+///
+/// struct Hello;
+///
+/// impl Service<&'static str> for Hello {
+///     type Response = String;
+///     type Error = Infallible;
+///     type Future = Ready<Result<String, Infallible>>;
+///
+///     fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+///         Poll::Ready(Ok(()))
+///     }
+///
+///     fn call(&mut self, name: &'static str) -> Self::Future {
+///         ready(Ok(format!("hello {name}")))
+///     }
+/// }
+///
+/// let mut service = LinearLayer.layer(Hello);
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let Poll::Ready(Ok(response)) = pin!(service.call("world")).poll(&mut cx) else {
+///     unreachable!()
+/// };
+/// assert_eq!(response.into(), "hello world");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearLayer;
+
+/// Service returning `LinearResponse`s, created by `LinearLayer`.
+#[derive(Debug, Clone)]
+pub struct LinearService<S>(S);
+
+/// Future of a `LinearService` response.
+#[must_use = "futures do nothing unless awaited"]
+pub struct ResponseFuture<F>(F);
+
+impl<S> Layer<S> for LinearLayer {
+    type Service = LinearService<S>;
+
+    fn layer(&self, inner: S) -> LinearService<S> {
+        LinearService(inner)
+    }
+}
+
+impl<S> LinearService<S> {
+    /// Returns the wrapped service.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: Service<Request>, Request> Service<Request> for LinearService<S> {
+    type Response = LinearResponse<S::Response>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        ResponseFuture(self.0.call(request))
+    }
+}
+
+impl<F: Future<Output = Result<R, E>>, R, E> Future for ResponseFuture<F> {
+    type Output = Result<LinearResponse<R>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the inner future is structurally pinned, it is never moved out of a pinned
+        // `ResponseFuture`, which has no `Drop` implementation either.
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.0) };
+        future
+            .poll(cx)
+            .map(|result| result.map(LinearResponse::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearLayer;
+    use core::future::{Future, Ready, ready};
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use tower_layer::Layer;
+    use tower_service::Service;
+
+    struct Double;
+
+    impl Service<u8> for Double {
+        type Response = u8;
+        type Error = &'static str;
+        type Future = Ready<Result<u8, &'static str>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), &'static str>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: u8) -> Self::Future {
+            ready(request.checked_mul(2).ok_or("overflow"))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn response_dropped() {
+        let mut service = LinearLayer.layer(Double);
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(matches!(
+            pin!(service.call(200)).poll(&mut cx),
+            Poll::Ready(Err("overflow"))
+        ));
+        let response = pin!(service.call(2)).poll(&mut cx);
+        assert!(matches!(response, Poll::Ready(Ok(_))));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::DropBomb;
+
+/// A transaction-like object bundled with the closures committing and rolling it back. The
+/// only consuming methods are `commit()` and `rollback()`, dropping the transaction aborts with
+/// "transaction neither committed nor rolled back".
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut tx = LinearTransaction::new(
+///     Vec::new(),
+///     |writes: Vec<&str>| writes.len(),
+///     |_: Vec<&str>| "rolled back",
+/// );
+/// tx.get_mut().push("insert");
+/// assert_eq!(tx.commit(), 1);
+/// ```
+#[must_use]
+pub struct LinearTransaction<T, C, R> {
+    tx: T,
+    commit: C,
+    rollback: R,
+    bomb: DropBomb,
+}
+
+impl<T, C, R> LinearTransaction<T, C, R> {
+    /// Wraps a transaction with its commit and rollback closures.
+    pub const fn new(tx: T, commit: C, rollback: R) -> Self {
+        Self {
+            tx,
+            commit,
+            rollback,
+            bomb: DropBomb::new("transaction neither committed nor rolled back"),
+        }
+    }
+
+    /// Returns a reference to the transaction.
+    pub const fn get(&self) -> &T {
+        &self.tx
+    }
+
+    /// Returns a mutable reference to the transaction, to run its statements.
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.tx
+    }
+
+    /// Commits the transaction and returns the result of the commit closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let tx = LinearTransaction::new(1, |x: i32| x + 1, |x: i32| x - 1);
+    /// assert_eq!(tx.commit(), 2);
+    /// ```
+    pub fn commit<O>(self) -> O
+    where
+        C: FnOnce(T) -> O,
+    {
+        self.bomb.defuse();
+        (self.commit)(self.tx)
+    }
+
+    /// Rolls the transaction back and returns the result of the rollback closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let tx = LinearTransaction::new(1, |x: i32| x + 1, |x: i32| x - 1);
+    /// assert_eq!(tx.rollback(), 0);
+    /// ```
+    pub fn rollback<O>(self) -> O
+    where
+        R: FnOnce(T) -> O,
+    {
+        self.bomb.defuse();
+        (self.rollback)(self.tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearTransaction;
+
+    type Writes = LinearTransaction<Vec<i32>, fn(Vec<i32>) -> Vec<i32>, fn(Vec<i32>) -> Vec<i32>>;
+
+    fn begin() -> Writes {
+        LinearTransaction::new(Vec::new(), |writes| writes, |_| Vec::new())
+    }
+
+    #[test]
+    fn committed_and_rolled_back() {
+        let mut db = vec![1];
+        let mut tx = begin();
+        tx.get_mut().push(2);
+        assert_eq!(tx.get(), &[2]);
+        db.extend(tx.commit());
+        let mut tx = begin();
+        tx.get_mut().push(3);
+        db.extend(tx.rollback());
+        assert_eq!(db, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction neither committed nor rolled back")]
+    fn dropped() {
+        let _ = LinearTransaction::new((), |()| (), |()| ());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{__linear_from_parts, Linear};
+use core::convert::Infallible;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+/// `?` on a `Linear<Result<T,E>>` yields a `Linear<T>` or returns the `Err` value linearly.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// fn parse_and_double(text: MustUse<&str>) -> MustUse<Result<i32, std::num::ParseIntError>> {
+///     let number = text.try_map(str::parse::<i32>)?;
+///     must_use(Ok(number.into() * 2))
+/// }
+///
+/// assert_eq!(parse_and_double(must_use("21")).unwrap_ok().into(), 42);
+/// parse_and_double(must_use("x")).unwrap_err().destroy();
+/// ```
+impl<T, E, U> Try for Linear<Result<T, E>, U> {
+    type Output = Linear<T, Self>;
+    type Residual = Linear<Result<Infallible, E>, Self>;
+
+    fn from_output(output: Self::Output) -> Self {
+        __linear_from_parts(Ok(output.into()))
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.into() {
+            Ok(t) => ControlFlow::Continue(__linear_from_parts(t)),
+            Err(e) => ControlFlow::Break(__linear_from_parts(Err(e))),
+        }
+    }
+}
+
+/// Converts the error of a linear residual with `From`, like `?` on a plain `Result`.
+impl<T, E, F: From<E>, U, V> FromResidual<Linear<Result<Infallible, E>, V>>
+    for Linear<Result<T, F>, U>
+{
+    fn from_residual(residual: Linear<Result<Infallible, E>, V>) -> Self {
+        let Err(e) = residual.into();
+        __linear_from_parts(Err(From::from(e)))
+    }
+}
+
+/// Ties the residual back to the linear result it came from.
+impl<T, E, U> Residual<Linear<T, Linear<Result<T, E>, U>>>
+    for Linear<Result<Infallible, E>, Linear<Result<T, E>, U>>
+{
+    type TryType = Linear<Result<T, E>, U>;
+}
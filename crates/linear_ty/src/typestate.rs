@@ -0,0 +1,128 @@
+/// Marks that a state machine generated by `typestate!` may transition into the state `S`.
+pub trait Transition<S> {}
+
+/// Generates a linear state machine. The states are zero sized types, the machine is a linear
+/// value carrying data of type `T` and is parameterized by its current state. Transitions are
+/// only available along the declared edges.
+///
+/// All states are declared in braces after the machine name, the first one is the initial
+/// state. The edges follow as chains of states, separated by `;`.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty::*;
+///
+/// typestate! {
+///     /// Job runner.
+///     pub Machine { Idle, Running, Done, Failed }
+///     Idle -> Running -> Done;
+///     Running -> Failed;
+/// }
+///
+/// let machine: Machine<Idle, u32> = Machine::new(1);
+/// let running = machine.transition::<Running>();
+/// let done: Machine<Done, String> = running.transition_with(|x| x.to_string());
+/// assert_eq!(done.into(), "1");
+/// ```
+///
+/// Undeclared transitions do not compile:
+///
+/// ```compile_fail
+/// use linear_ty::*;
+///
+/// typestate! {
+///     pub Machine { Idle, Running, Done }
+///     Idle -> Running -> Done;
+/// }
+///
+/// let done = Machine::new(()).transition::<Done>();
+/// # done.into();
+/// ```
+#[macro_export]
+macro_rules! typestate {
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis $machine:ident { $initial:ident $(, $state:ident)* $(,)? }
+        $($edges:tt)*
+    ) => {
+        $(#[$($meta)*])*
+        #[must_use]
+        $vis struct $machine<S, T = ()>($crate::Linear<T, S>);
+
+        #[doc = concat!("State `", stringify!($initial), "` of [`", stringify!($machine), "`].")]
+        $vis struct $initial;
+        $(
+            #[doc = concat!("State `", stringify!($state), "` of [`", stringify!($machine), "`].")]
+            $vis struct $state;
+        )*
+
+        impl<T> $machine<$initial, T> {
+            /// Constructs a new machine in the initial state.
+            pub const fn new(value: T) -> Self {
+                Self($crate::__linear_from_parts(value))
+            }
+        }
+
+        impl<S, T> $machine<S, T> {
+            /// Transitions into the state `N`, only available along declared edges.
+            pub fn transition<N>(self) -> $machine<N, T>
+            where
+                Self: $crate::Transition<N>,
+            {
+                $machine($crate::__linear_from_parts(self.0.into()))
+            }
+
+            /// Transitions into the state `N` while transforming the carried data.
+            pub fn transition_with<N, F: FnOnce(T) -> R, R>(self, f: F) -> $machine<N, R>
+            where
+                Self: $crate::Transition<N>,
+            {
+                $machine($crate::__linear_from_parts(f(self.0.into())))
+            }
+
+            /// Destructures the machine and returns the carried data.
+            pub fn into(self) -> T {
+                self.0.into()
+            }
+        }
+
+        $crate::typestate!(@edges $machine; $($edges)*);
+    };
+    (@edges $machine:ident;) => {};
+    (@edges $machine:ident; $from:ident -> $to:ident $(-> $rest:ident)*; $($more:tt)*) => {
+        impl<T> $crate::Transition<$to> for $machine<$from, T> {}
+        $crate::typestate!(@edges $machine; $to $(-> $rest)*; $($more)*);
+    };
+    (@edges $machine:ident; $last:ident; $($more:tt)*) => {
+        $crate::typestate!(@edges $machine; $($more)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    typestate! {
+        /// Test machine.
+        pub Machine { Idle, Running, Done, Failed }
+        Idle -> Running -> Done;
+        Running -> Failed;
+        Failed -> Idle;
+    }
+
+    #[test]
+    fn transitions() {
+        let machine = Machine::new(0u8).transition::<Running>();
+        let failed: Machine<Failed, u8> = machine.transition_with(|x| x + 1);
+        let done = failed
+            .transition::<Idle>()
+            .transition::<Running>()
+            .transition::<Done>();
+        assert_eq!(done.into(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = Machine::new(()).transition::<Running>();
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{__linear_from_parts, Linear, LinearIter};
+
+/// A vector of linear elements. `push()` takes `Linear<T>` elements and iterating yields them
+/// as `Linear<T>` again. The vector itself is linear, it must be either drained with
+/// `into_linear_iter()`, which must be driven to exhaustion, or finished with `finish_empty()`
+/// once it is empty.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut requests = LinearVec::new();
+/// for id in 0..3 {
+///     requests.push(must_use(id));
+/// }
+/// assert_eq!(requests.len(), 3);
+/// let sum: i32 = requests.into_linear_iter().map(Linear::into).sum();
+/// assert_eq!(sum, 3);
+/// ```
+#[must_use]
+pub struct LinearVec<T, U>(Linear<Vec<T>, U>);
+
+impl<T, U> LinearVec<T, U> {
+    /// Creates an empty vector.
+    pub const fn new() -> Self {
+        Self(__linear_from_parts(Vec::new()))
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.0.len()
+    }
+
+    /// Returns whether the vector has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.0.is_empty()
+    }
+
+    /// Appends a linear element.
+    pub fn push(&mut self, item: Linear<T, U>) {
+        self.0.0.push(item.into());
+    }
+
+    /// Removes the last element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut vec = LinearVec::new();
+    /// vec.push(must_use("a"));
+    /// assert_eq!(vec.pop().unwrap().into(), "a");
+    /// assert!(vec.pop().is_none());
+    /// # vec.finish_empty().ok().unwrap();
+    /// ```
+    pub fn pop(&mut self) -> Option<Linear<T, U>> {
+        self.0.0.pop().map(__linear_from_parts)
+    }
+
+    /// Drains the vector into a linear iterator yielding its elements.
+    pub fn into_linear_iter(self) -> LinearIter<T, U> {
+        LinearIter::from_vec(self.0.into())
+    }
+
+    /// Finishes an empty vector.
+    ///
+    /// # Errors
+    ///
+    /// When elements are left the vector is returned as `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// let mut vec = LinearVec::new();
+    /// vec.push(must_use(1));
+    /// let mut vec = vec.finish_empty().err().unwrap();
+    /// vec.pop().unwrap().destroy();
+    /// assert!(vec.finish_empty().is_ok());
+    /// ```
+    pub fn finish_empty(self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.0.destroy();
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, U> Default for LinearVec<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, U> Extend<Linear<T, U>> for LinearVec<T, U> {
+    fn extend<I: IntoIterator<Item = Linear<T, U>>>(&mut self, iter: I) {
+        self.0.0.extend(iter.into_iter().map(Linear::into));
+    }
+}
+
+impl<T, U> FromIterator<Linear<T, U>> for LinearVec<T, U> {
+    fn from_iter<I: IntoIterator<Item = Linear<T, U>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, U> IntoIterator for LinearVec<T, U> {
+    type Item = Linear<T, U>;
+    type IntoIter = LinearIter<T, U>;
+
+    fn into_iter(self) -> LinearIter<T, U> {
+        self.into_linear_iter()
+    }
+}
+
+/// A stack of linear elements for nested obligations, e.g. matching begin and end markers.
+/// `push()` takes `Linear<T>` elements and `pop()` returns them in reverse order, the stack can
+/// only be finished with `finish()` once it is empty.
+///
+/// # Example
+///
+/// ```rust
+/// # use linear_ty::*;
+/// let mut open = LinearStack::new();
+/// let mut out = String::new();
+/// for tag in ["html", "body"] {
+///     out += &format!("<{tag}>");
+///     open.push(must_use(tag));
+/// }
+/// while let Some(tag) = open.pop() {
+///     out += &format!("</{}>", tag.into());
+/// }
+/// open.finish().ok().unwrap();
+/// assert_eq!(out, "<html><body></body></html>");
+/// ```
+#[must_use]
+pub struct LinearStack<T, U>(LinearVec<T, U>);
+
+impl<T, U> LinearStack<T, U> {
+    /// Creates an empty stack.
+    pub const fn new() -> Self {
+        Self(LinearVec::new())
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the stack has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Pushes a linear element.
+    pub fn push(&mut self, item: Linear<T, U>) {
+        self.0.push(item);
+    }
+
+    /// Pops the most recently pushed element.
+    pub fn pop(&mut self) -> Option<Linear<T, U>> {
+        self.0.pop()
+    }
+
+    /// Finishes an empty stack.
+    ///
+    /// # Errors
+    ///
+    /// When elements are left the stack is returned as `Err`.
+    pub fn finish(self) -> Result<(), Self> {
+        self.0.finish_empty().map_err(Self)
+    }
+}
+
+impl<T, U> Default for LinearStack<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearStack, LinearVec};
+    use crate::must_use;
+
+    #[test]
+    fn fan_out() {
+        let vec: LinearVec<_, _> = (1..=4).map(must_use).collect();
+        let mut doubled = LinearVec::new();
+        for item in vec {
+            doubled.push(must_use(item.into() * 2));
+        }
+        assert_eq!(
+            doubled
+                .into_linear_iter()
+                .map(crate::Linear::into)
+                .sum::<i32>(),
+            20
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped_empty() {
+        let _ = LinearVec::<u8, ()>::new();
+    }
+
+    #[test]
+    fn nested() {
+        let mut stack = LinearStack::new();
+        stack.push(must_use(1));
+        stack.push(must_use(2));
+        assert_eq!(stack.len(), 2);
+        let mut stack = stack.finish().err().unwrap();
+        assert_eq!(stack.pop().unwrap().into(), 2);
+        assert_eq!(stack.pop().unwrap().into(), 1);
+        assert!(stack.is_empty());
+        stack.finish().ok().unwrap();
+    }
+}
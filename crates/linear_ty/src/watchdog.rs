@@ -0,0 +1,216 @@
+use crate::Linear;
+use core::panic::Location;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+/// A linear value registered with the watchdog by `Linear::with_deadline()`. It is reported
+/// once when it is not consumed before its deadline, consuming it deregisters it.
+#[must_use]
+pub struct Watched<T, U> {
+    linear: Linear<T, U>,
+    id: u64,
+}
+
+/// Report of a watched value not consumed before its deadline.
+#[derive(Debug, Clone)]
+pub struct Overdue {
+    /// Type of the watched value.
+    pub type_name: &'static str,
+    /// Where the value was registered.
+    pub location: &'static Location<'static>,
+    /// The deadline the value was registered with.
+    pub deadline: Duration,
+}
+
+impl core::fmt::Display for Overdue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "linear {} registered at {} not consumed within {:?}",
+            self.type_name, self.location, self.deadline
+        )
+    }
+}
+
+type Handler = Arc<dyn Fn(&Overdue) + Send + Sync>;
+
+struct Entry {
+    expires: Instant,
+    overdue: Overdue,
+}
+
+struct State {
+    next_id: u64,
+    entries: BTreeMap<u64, Entry>,
+    handler: Option<Handler>,
+    running: bool,
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    next_id: 0,
+    entries: BTreeMap::new(),
+    handler: None,
+    running: false,
+});
+static REGISTERED: Condvar = Condvar::new();
+
+fn state() -> MutexGuard<'static, State> {
+    // The state is consistent between statements, a poisoned lock holds a valid state.
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets the handler invoked with each overdue value, replacing the default which prints the
+/// report to stderr. The handler runs on the watchdog thread.
+///
+/// # Example
+///
+/// ```rust
+/// linear_ty::watchdog::set_handler(|overdue| eprintln!("stuck obligation: {overdue}"));
+/// ```
+pub fn set_handler<F: Fn(&Overdue) + Send + Sync + 'static>(handler: F) {
+    state().handler = Some(Arc::new(handler));
+}
+
+/// Additional `with_deadline()` method for any `Linear<T>`, available with the `watchdog`
+/// feature.
+impl<T, U> Linear<T, U> {
+    /// Registers the value with a background watchdog which reports it when it is not consumed
+    /// within `deadline`. Stuck obligations in long running services surface while the
+    /// service runs rather than at exit. The watchdog thread is started on first use.
+    ///
+    /// # Panics
+    ///
+    /// When the watchdog thread can not be spawned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linear_ty::*;
+    /// use std::time::Duration;
+    ///
+    /// let request = new_linear!("request").with_deadline(Duration::from_secs(30));
+    /// // ... handle the request
+    /// assert_eq!(request.into(), "request");
+    /// ```
+    #[track_caller]
+    pub fn with_deadline(self, deadline: Duration) -> Watched<T, U> {
+        let mut state = state();
+        let id = state.next_id;
+        state.next_id += 1;
+        // A deadline beyond the range of `Instant` never expires and is not registered.
+        let Some(expires) = Instant::now().checked_add(deadline) else {
+            return Watched { linear: self, id };
+        };
+        let entry = Entry {
+            expires,
+            overdue: Overdue {
+                type_name: core::any::type_name::<T>(),
+                location: Location::caller(),
+                deadline,
+            },
+        };
+        state.entries.insert(id, entry);
+        if !state.running {
+            std::thread::Builder::new()
+                .name(String::from("linear_ty watchdog"))
+                .spawn(watch)
+                .expect("failed to spawn the watchdog thread");
+            state.running = true;
+        }
+        REGISTERED.notify_one();
+        Watched { linear: self, id }
+    }
+}
+
+impl<T, U> Watched<T, U> {
+    /// Deregisters the value and returns it as plain linear value.
+    pub fn into_linear(self) -> Linear<T, U> {
+        state().entries.remove(&self.id);
+        self.linear
+    }
+
+    /// Deregisters the value and consumes it, like `Linear::into()`.
+    pub fn into(self) -> T {
+        self.into_linear().into()
+    }
+}
+
+fn watch() {
+    let mut state = state();
+    loop {
+        let now = Instant::now();
+        let mut overdue = Vec::new();
+        let mut next = None::<Instant>;
+        state.entries.retain(|_, entry| {
+            if entry.expires <= now {
+                overdue.push(entry.overdue.clone());
+                // reported once, the value stays linear and is still consumed later
+                false
+            } else {
+                next = Some(next.map_or(entry.expires, |next| next.min(entry.expires)));
+                true
+            }
+        });
+        if !overdue.is_empty() {
+            let handler = state.handler.clone();
+            drop(state);
+            for overdue in &overdue {
+                match &handler {
+                    Some(handler) => handler(overdue),
+                    None => eprintln!("{overdue}"),
+                }
+            }
+            state = self::state();
+            continue;
+        }
+        state = match next {
+            Some(next) => {
+                REGISTERED
+                    .wait_timeout(state, next.saturating_duration_since(now))
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => REGISTERED
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::new_linear;
+    use core::time::Duration;
+    use std::sync::Mutex;
+    use std::sync::mpsc;
+
+    #[test]
+    fn reported() {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        super::set_handler(move |overdue| {
+            let _ = tx.lock().unwrap().send(overdue.type_name);
+        });
+        let watched = new_linear!(1u8).with_deadline(Duration::ZERO);
+        let consumed = new_linear!(2u16).with_deadline(Duration::from_millis(50));
+        consumed.into_linear().destroy();
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok("u8"));
+        assert_eq!(watched.into(), 1);
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+
+    #[test]
+    fn never_expires() {
+        let watched = new_linear!(3u32).with_deadline(Duration::MAX);
+        assert!(!super::state().entries.contains_key(&watched.id));
+        assert_eq!(watched.into(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "linear type dropped")]
+    fn dropped() {
+        let _ = new_linear!(()).with_deadline(Duration::from_secs(60));
+    }
+}
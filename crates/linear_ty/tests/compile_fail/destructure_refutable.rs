@@ -0,0 +1,7 @@
+use linear_ty::{destructure, new_linear};
+
+fn main() {
+    let option = new_linear!(Some(1));
+    destructure!(option => Some(value));
+    assert_eq!(value, 1);
+}
@@ -0,0 +1,185 @@
+//! Derive and attribute macros for the `linear_ty` crate.
+//!
+//! `#[derive(Linear)]` generates a companion `Linear<Name>` newtype for a user type. The
+//! companion is generated by the `linear!` macro, it must be consumed and panics when dropped.
+//! `#[must_consume]` wraps the return value of a function in a `MustUse<T>`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use linear_ty_derive::Linear;
+//!
+//! #[derive(Linear)]
+//! pub struct Connection {
+//!     id: u32,
+//! }
+//!
+//! let connection = Connection { id: 1 }.into_linear();
+//! assert_eq!(connection.consume(|connection| connection.id), 1);
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, GenericParam, ItemFn, ReturnType, Type, parse_macro_input};
+
+/// Generates a companion `Linear<Name>` newtype wrapping the annotated type along with a
+/// `From` conversion and an `into_linear()` constructor on the annotated type.
+///
+/// Type parameters are supported, their bounds are moved into the `where` clause of the
+/// newtype. Lifetime and const parameters are not supported.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty_derive::Linear;
+///
+/// #[derive(Linear)]
+/// struct Request<T: Clone>(T);
+///
+/// let request: LinearRequest<_> = Request("GET /").into();
+/// let Request(path) = request.into();
+/// assert_eq!(path, "GET /");
+/// ```
+#[proc_macro_derive(Linear)]
+pub fn derive_linear(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_linear(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_linear(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let vis = &input.vis;
+    let name = &input.ident;
+    let linear_name = format_ident!("Linear{}", name);
+    let doc = format!("Linear wrapper of [`{name}`], generated by `#[derive(Linear)]`.");
+
+    let mut params = Vec::new();
+    let mut predicates = Vec::new();
+    for param in &input.generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                let bounds = &param.bounds;
+                if !bounds.is_empty() {
+                    predicates.push(quote!(#ident: #bounds));
+                }
+                params.push(ident);
+            }
+            GenericParam::Lifetime(param) => {
+                return Err(syn::Error::new_spanned(
+                    param,
+                    "#[derive(Linear)] does not support lifetime parameters",
+                ));
+            }
+            GenericParam::Const(param) => {
+                return Err(syn::Error::new_spanned(
+                    param,
+                    "#[derive(Linear)] does not support const parameters",
+                ));
+            }
+        }
+    }
+    if let Some(where_clause) = &input.generics.where_clause {
+        predicates.extend(where_clause.predicates.iter().map(|p| quote!(#p)));
+    }
+
+    let generics = if params.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#params),*>)
+    };
+    let where_clause = if predicates.is_empty() {
+        quote!()
+    } else {
+        quote!(where #(#predicates),*)
+    };
+
+    // The newtype is generated by the internal helper of `linear!` directly, `linear!` would
+    // take two type parameters without a `where` clause for a `Linear<T, U>` like type.
+    Ok(quote! {
+        ::linear_ty::__linear_newtype! {
+            @start {
+                #[doc = #doc]
+                #vis struct #linear_name [#(#params)*] (#name #generics) (#name #generics)
+            } #where_clause;
+        }
+
+        impl #generics ::core::convert::From<#name #generics> for #linear_name #generics
+        #where_clause
+        {
+            fn from(value: #name #generics) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl #generics #name #generics
+        #where_clause
+        {
+            /// Wraps the value in its linear companion type.
+            #vis fn into_linear(self) -> #linear_name #generics {
+                #linear_name::new(self)
+            }
+        }
+    })
+}
+
+/// Wraps the return value of a function in a `MustUse<T>`. What was a `#[must_use]` lint
+/// becomes a hard guarantee, the caller must consume the returned value.
+///
+/// Works with `async` functions as well, the awaited value is wrapped then.
+///
+/// # Example
+///
+/// ```rust
+/// use linear_ty_derive::must_consume;
+///
+/// #[must_consume]
+/// fn parse(text: &str) -> Result<u32, std::num::ParseIntError> {
+///     let number = text.parse()?;
+///     Ok(number)
+/// }
+///
+/// let number = parse("42").unwrap_ok();
+/// assert_eq!(number.into(), 42);
+/// ```
+#[proc_macro_attribute]
+pub fn must_consume(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = TokenStream2::from(attr);
+    let item = parse_macro_input!(item as ItemFn);
+    expand_must_consume(&attr, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_must_consume(attr: &TokenStream2, mut item: ItemFn) -> syn::Result<TokenStream2> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[must_consume] does not take arguments",
+        ));
+    }
+    let ReturnType::Type(_, ty) = item.sig.output.clone() else {
+        return Err(syn::Error::new_spanned(
+            &item.sig,
+            "#[must_consume] requires a function with a return value",
+        ));
+    };
+    item.sig.output = syn::parse_quote!(-> ::linear_ty::MustUse<#ty>);
+
+    // The body is moved into a closure or async block so that `return` and `?` keep their
+    // meaning within the body. Closures can not be annotated with `impl Trait` return types.
+    // The closure is passed to a function taking `FnOnce`, a closure called in place would be
+    // inferred as `FnMut` which can not return borrows of its captures.
+    let body = &item.block;
+    let block = if item.sig.asyncness.is_some() {
+        syn::parse_quote!({ ::linear_ty::must_use(async move #body.await) })
+    } else if matches!(*ty, Type::ImplTrait(_)) {
+        syn::parse_quote!({ ::linear_ty::__must_consume(move || #body) })
+    } else {
+        syn::parse_quote!({ ::linear_ty::__must_consume(move || -> #ty #body) })
+    };
+    item.block = block;
+    Ok(quote!(#item))
+}
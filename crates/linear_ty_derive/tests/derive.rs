@@ -0,0 +1,113 @@
+//! Tests of `#[derive(Linear)]`.
+
+use linear_ty_derive::Linear;
+
+/// Named fields.
+#[derive(Debug, PartialEq, Linear)]
+pub struct Token {
+    id: u64,
+    secret: String,
+}
+
+/// Generic enum with a `where` clause.
+#[derive(Linear)]
+pub enum State<T>
+where
+    T: Default,
+{
+    /// Not running.
+    Idle,
+    /// Running with a value.
+    Running(T),
+}
+
+/// Two type parameters without a `where` clause.
+#[derive(Linear)]
+pub struct Pair<A, B>(A, B);
+
+#[test]
+fn struct_wrapper() {
+    let token = Token {
+        id: 1,
+        secret: String::from("secret"),
+    }
+    .into_linear();
+    let id = token.map(|token| token.id);
+    assert_eq!(id.into(), 1);
+}
+
+#[test]
+fn generic_enum_wrapper() {
+    let state: LinearState<u8> = State::Running(7).into();
+    let value = state.consume(|state| match state {
+        State::Idle => 0,
+        State::Running(value) => value,
+    });
+    assert_eq!(value, 7);
+    LinearState::new(State::<u8>::Idle).destroy();
+}
+
+#[test]
+fn two_parameter_wrapper() {
+    let pair = Pair(1, "b").into_linear();
+    let Pair(a, b) = pair.into();
+    assert_eq!((a, b), (1, "b"));
+}
+
+#[linear_ty_derive::must_consume]
+fn checked_div(a: u32, b: u32) -> Option<u32> {
+    if b == 0 {
+        return None;
+    }
+    Some(a / b)
+}
+
+#[test]
+fn must_consume() {
+    assert_eq!(checked_div(6, 3).unwrap_some().into(), 2);
+    assert_eq!(checked_div(6, 0).into(), None);
+}
+
+/// Counter handing out a reference to its count.
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    #[linear_ty_derive::must_consume]
+    fn count_mut(&mut self) -> &mut u32 {
+        &mut self.count
+    }
+}
+
+#[test]
+fn must_consume_reference() {
+    let mut counter = Counter { count: 1 };
+    *counter.count_mut().into() += 1;
+    assert_eq!(counter.count, 2);
+}
+
+#[test]
+fn must_consume_async() {
+    #[linear_ty_derive::must_consume]
+    async fn answer() -> u32 {
+        42
+    }
+
+    let mut future = core::pin::pin!(answer());
+    let mut context = core::task::Context::from_waker(core::task::Waker::noop());
+    match future.as_mut().poll(&mut context) {
+        core::task::Poll::Ready(answer) => assert_eq!(answer.into(), 42),
+        core::task::Poll::Pending => unreachable!(),
+    }
+}
+
+#[linear_ty_derive::must_consume]
+fn evens(limit: u32) -> impl Iterator<Item = u32> {
+    (0..limit).filter(|x| x % 2 == 0)
+}
+
+#[test]
+fn must_consume_impl_trait() {
+    assert_eq!(evens(5).consume(Iterator::sum::<u32>), 6);
+}